@@ -65,6 +65,11 @@ const MUST_FREE: &str = " The returned instance has to be freed manually with th
                          according `*_free` function or indirecly with another function \
                          that consumes and frees it.";
 const FREES: &str = " Frees the input instance.";
+const STATUS: &str = " Returns 0 on success. Otherwise, it returns 1 if the file name \
+                      is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization \
+                      errors; in this case, if `error` is not null, an error message is \
+                      written into `error`, truncated to `error_len - 1` bytes and \
+                      null-terminated.";
 
 #[proc_macro]
 pub fn raw_vec(input: TokenStream) -> TokenStream {
@@ -117,6 +122,30 @@ pub fn basic(input: TokenStream) -> TokenStream {
     let serialize_bin = pre.name("serialize_bin");
     let deserialize_bin = pre.name("deserialize_bin");
 
+    // writes the error message into the caller's buffer (truncated and null-terminated)
+    // and returns the status code
+    let report = quote! {
+        fn report(
+            code: i32,
+            message: String,
+            error: *mut std::ffi::c_char,
+            error_len: usize,
+        ) -> i32 {
+            if !error.is_null() && error_len > 0 {
+                let len = message.len().min(error_len - 1);
+                unsafe {
+                    std::ptr::copy_nonoverlapping(
+                        message.as_ptr() as *const std::ffi::c_char,
+                        error,
+                        len,
+                    );
+                    *error.add(len) = 0;
+                }
+            }
+            code
+        }
+    };
+
     quote! {
         #[doc = #MUST_FREE]
         #[no_mangle]
@@ -131,53 +160,117 @@ pub fn basic(input: TokenStream) -> TokenStream {
         }
 
         /// Serialize into json.
-        #[no_mangle]
-        pub unsafe extern "C"
-        fn #serialize(x: &#typ, file: *const std::ffi::c_char) {
-            let file = unsafe {
-                std::ffi::CStr::from_ptr(file as *const i8)
-            }.to_str().expect("invalid file name");
-            let output = serde_json::to_string(x).expect("serialize error");
-            std::fs::write(file, output).unwrap();
+        ///
+        #[doc = #STATUS]
+        #[no_mangle]
+        pub unsafe extern "C" fn #serialize(
+            x: &#typ,
+            file: *const std::ffi::c_char,
+            error: *mut std::ffi::c_char,
+            error_len: usize,
+        ) -> i32 {
+            #report
+            let file = match unsafe { std::ffi::CStr::from_ptr(file) }.to_str() {
+                Ok(file) => file,
+                Err(e) => return report(1, format!("invalid file name: {e}"), error, error_len),
+            };
+            let output = match serde_json::to_string(x) {
+                Ok(output) => output,
+                Err(e) => return report(3, format!("serialize error: {e}"), error, error_len),
+            };
+            match std::fs::write(file, output) {
+                Ok(()) => 0,
+                Err(e) => report(2, format!("cannot write file: {e}"), error, error_len),
+            }
         }
 
-        /// Deserialize from json.
+        /// Deserialize from json. On success, the deserialized instance is written into
+        /// `out`.
+        ///
+        #[doc = #STATUS]
         ///
         #[doc = #MUST_FREE]
         #[no_mangle]
-        pub extern "C"
-        fn #deserialize(file: *const std::ffi::c_char) -> *mut #typ {
-            let file = unsafe {
-                std::ffi::CStr::from_ptr(file as *const i8)
-            }.to_str().expect("invalid file name");
-            let contents = std::fs::read_to_string(file).expect("cannot read file");
-            let x: #typ = serde_json::from_str(&contents).expect("deserialize error");
-            std::mem::ManuallyDrop::new(Box::new(x)).as_mut() as *mut #typ
+        pub unsafe extern "C" fn #deserialize(
+            file: *const std::ffi::c_char,
+            out: *mut *mut #typ,
+            error: *mut std::ffi::c_char,
+            error_len: usize,
+        ) -> i32 {
+            #report
+            let file = match unsafe { std::ffi::CStr::from_ptr(file) }.to_str() {
+                Ok(file) => file,
+                Err(e) => return report(1, format!("invalid file name: {e}"), error, error_len),
+            };
+            let contents = match std::fs::read_to_string(file) {
+                Ok(contents) => contents,
+                Err(e) => return report(2, format!("cannot read file: {e}"), error, error_len),
+            };
+            let x: #typ = match serde_json::from_str(&contents) {
+                Ok(x) => x,
+                Err(e) => return report(3, format!("deserialize error: {e}"), error, error_len),
+            };
+            unsafe {
+                *out = std::mem::ManuallyDrop::new(Box::new(x)).as_mut() as *mut #typ;
+            }
+            0
         }
 
         /// Serialize into binary code.
-        #[no_mangle]
-        pub unsafe extern "C"
-        fn #serialize_bin(x: &#typ, file: *const std::ffi::c_char) {
-            let file = unsafe {
-                std::ffi::CStr::from_ptr(file as *const i8)
-            }.to_str().expect("invalid file name");
-            let output = bincode::serialize(x).expect("serialize error");
-            std::fs::write(file, std::str::from_utf8(&output).unwrap()).unwrap();
+        ///
+        #[doc = #STATUS]
+        #[no_mangle]
+        pub unsafe extern "C" fn #serialize_bin(
+            x: &#typ,
+            file: *const std::ffi::c_char,
+            error: *mut std::ffi::c_char,
+            error_len: usize,
+        ) -> i32 {
+            #report
+            let file = match unsafe { std::ffi::CStr::from_ptr(file) }.to_str() {
+                Ok(file) => file,
+                Err(e) => return report(1, format!("invalid file name: {e}"), error, error_len),
+            };
+            let output = match bincode::serialize(x) {
+                Ok(output) => output,
+                Err(e) => return report(3, format!("serialize error: {e}"), error, error_len),
+            };
+            match std::fs::write(file, output) {
+                Ok(()) => 0,
+                Err(e) => report(2, format!("cannot write file: {e}"), error, error_len),
+            }
         }
 
-        /// Deserialize from binary codeh.
+        /// Deserialize from binary code. On success, the deserialized instance is written
+        /// into `out`.
+        ///
+        #[doc = #STATUS]
         ///
         #[doc = #MUST_FREE]
         #[no_mangle]
-        pub extern "C"
-        fn #deserialize_bin(file: *const std::ffi::c_char) -> *mut #typ {
-            let file = unsafe {
-                std::ffi::CStr::from_ptr(file as *const i8)
-            }.to_str().expect("invalid file name");
-            let contents = std::fs::read_to_string(file).expect("cannot read file");
-            let x: #typ = bincode::deserialize(contents.as_bytes()).expect("deserialize error");
-            std::mem::ManuallyDrop::new(Box::new(x)).as_mut() as *mut #typ
+        pub unsafe extern "C" fn #deserialize_bin(
+            file: *const std::ffi::c_char,
+            out: *mut *mut #typ,
+            error: *mut std::ffi::c_char,
+            error_len: usize,
+        ) -> i32 {
+            #report
+            let file = match unsafe { std::ffi::CStr::from_ptr(file) }.to_str() {
+                Ok(file) => file,
+                Err(e) => return report(1, format!("invalid file name: {e}"), error, error_len),
+            };
+            let contents = match std::fs::read(file) {
+                Ok(contents) => contents,
+                Err(e) => return report(2, format!("cannot read file: {e}"), error, error_len),
+            };
+            let x: #typ = match bincode::deserialize(&contents) {
+                Ok(x) => x,
+                Err(e) => return report(3, format!("deserialize error: {e}"), error, error_len),
+            };
+            unsafe {
+                *out = std::mem::ManuallyDrop::new(Box::new(x)).as_mut() as *mut #typ;
+            }
+            0
         }
     }
     .into()
@@ -203,19 +296,21 @@ pub fn pauli_stack(input: TokenStream) -> TokenStream {
         mut additional,
     } = parse_macro_input!(input as GenWithAdditional);
 
-    let x = pre.name("z");
-    let z = pre.name("x");
+    let x = pre.name("x");
+    let z = pre.name("z");
     let inner_type = additional.pop().unwrap();
 
     quote! {
+        /// Get a pointer to the X component of the stack.
         #[no_mangle]
-        pub extern "C" fn #z(x: &mut #typ) -> *mut #inner_type
+        pub extern "C" fn #x(x: &mut #typ) -> *mut #inner_type
         {
             &mut x.x as *mut #inner_type
         }
 
+        /// Get a pointer to the Z component of the stack.
         #[no_mangle]
-        pub extern "C" fn #x(x: &mut #typ) -> *mut #inner_type
+        pub extern "C" fn #z(x: &mut #typ) -> *mut #inner_type
         {
             &mut x.z as *mut #inner_type
         }
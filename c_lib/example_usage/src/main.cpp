@@ -34,9 +34,9 @@ int main(void) {
   size_t num_frames = frames_hmpsvbfx_frames_num(frames);
 
   fs::create_directories("output");
-  map_psvbfx_serialize(storage, "output/frames.json");
-  live_hmpefx_serialize(live, "output/live.json");
-  live_bvpt_serialize(tuple_live, "output/tuple_live.json");
+  map_psvbfx_serialize(storage, "output/frames.json", NULL, 0);
+  live_hmpefx_serialize(live, "output/live.json", NULL, 0);
+  live_bvpt_serialize(tuple_live, "output/tuple_live.json", NULL, 0);
 
   // below we will transpose it which requires that all stacks have the same
   // length (required by *_new_unchecked)
@@ -52,13 +52,26 @@ int main(void) {
   // frees frames_rebuilt, but we'll need to free transposed
   BufferedVector_psvb *transposed =
       frames_hmpsvbfx_stacked_transpose(frames_rebuilt, num_bits);
-  buffered_vector_psvb_serialize(transposed, "output/frames_transposed.json");
+  buffered_vector_psvb_serialize(transposed, "output/frames_transposed.json",
+                                 NULL, 0);
 
   buffered_vector_psvb_free(transposed);
   frames_hmpsvbfx_free(frames);
   live_bvpt_free(tuple_live);
   live_hmpefx_free(live);
 
+  // (de)serialization errors are reported via the status code and the error
+  // buffer instead of aborting
+  char error[256];
+  Live_bvpt *missing = NULL;
+  int32_t status = live_bvpt_deserialize("output/does_not_exist.json", &missing,
+                                         error, sizeof(error));
+  if (status != 2 || missing != NULL) {
+    printf("expected an IO error\n");
+    return 1;
+  }
+  printf("%d: %s\n", status, error);
+
   Vec_b *v = vec_b_new();
   printf("%d\n", vec_b_is_empty(v));
   vec_b_free(v);
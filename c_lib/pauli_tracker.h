@@ -462,27 +462,35 @@ void vec_b_free(Vec_b *x);
 
 /**
  * Serialize into json.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  */
-void vec_b_serialize(const Vec_b *x, const char *file);
+int32_t vec_b_serialize(const Vec_b *x, const char *file, char *error, uintptr_t error_len);
 
 /**
- * Deserialize from json.
+ * Deserialize from json. On success, the deserialized instance is written into `out`.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  *
  * The returned instance has to be freed manually with the according `*_free` function or indirecly with another function that consumes and frees it.
  */
-Vec_b *vec_b_deserialize(const char *file);
+int32_t vec_b_deserialize(const char *file, Vec_b **out, char *error, uintptr_t error_len);
 
 /**
  * Serialize into binary code.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  */
-void vec_b_serialize_bin(const Vec_b *x, const char *file);
+int32_t vec_b_serialize_bin(const Vec_b *x, const char *file, char *error, uintptr_t error_len);
 
 /**
- * Deserialize from binary codeh.
+ * Deserialize from binary code. On success, the deserialized instance is written into `out`.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  *
  * The returned instance has to be freed manually with the according `*_free` function or indirecly with another function that consumes and frees it.
  */
-Vec_b *vec_b_deserialize_bin(const char *file);
+int32_t vec_b_deserialize_bin(const char *file, Vec_b **out, char *error, uintptr_t error_len);
 
 bool vec_b_get(Vec_b *x, uintptr_t key);
 
@@ -504,27 +512,35 @@ void bitvec_free(struct BitVec *x);
 
 /**
  * Serialize into json.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  */
-void bitvec_serialize(const struct BitVec *x, const char *file);
+int32_t bitvec_serialize(const struct BitVec *x, const char *file, char *error, uintptr_t error_len);
 
 /**
- * Deserialize from json.
+ * Deserialize from json. On success, the deserialized instance is written into `out`.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  *
  * The returned instance has to be freed manually with the according `*_free` function or indirecly with another function that consumes and frees it.
  */
-struct BitVec *bitvec_deserialize(const char *file);
+int32_t bitvec_deserialize(const char *file, struct BitVec **out, char *error, uintptr_t error_len);
 
 /**
  * Serialize into binary code.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  */
-void bitvec_serialize_bin(const struct BitVec *x, const char *file);
+int32_t bitvec_serialize_bin(const struct BitVec *x, const char *file, char *error, uintptr_t error_len);
 
 /**
- * Deserialize from binary codeh.
+ * Deserialize from binary code. On success, the deserialized instance is written into `out`.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  *
  * The returned instance has to be freed manually with the according `*_free` function or indirecly with another function that consumes and frees it.
  */
-struct BitVec *bitvec_deserialize_bin(const char *file);
+int32_t bitvec_deserialize_bin(const char *file, struct BitVec **out, char *error, uintptr_t error_len);
 
 bool bitvec_get(struct BitVec *x, uintptr_t key);
 
@@ -553,27 +569,35 @@ void map_psvbfx_free(Map_psvbfx *x);
 
 /**
  * Serialize into json.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  */
-void map_psvbfx_serialize(const Map_psvbfx *x, const char *file);
+int32_t map_psvbfx_serialize(const Map_psvbfx *x, const char *file, char *error, uintptr_t error_len);
 
 /**
- * Deserialize from json.
+ * Deserialize from json. On success, the deserialized instance is written into `out`.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  *
  * The returned instance has to be freed manually with the according `*_free` function or indirecly with another function that consumes and frees it.
  */
-Map_psvbfx *map_psvbfx_deserialize(const char *file);
+int32_t map_psvbfx_deserialize(const char *file, Map_psvbfx **out, char *error, uintptr_t error_len);
 
 /**
  * Serialize into binary code.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  */
-void map_psvbfx_serialize_bin(const Map_psvbfx *x, const char *file);
+int32_t map_psvbfx_serialize_bin(const Map_psvbfx *x, const char *file, char *error, uintptr_t error_len);
 
 /**
- * Deserialize from binary codeh.
+ * Deserialize from binary code. On success, the deserialized instance is written into `out`.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  *
  * The returned instance has to be freed manually with the according `*_free` function or indirecly with another function that consumes and frees it.
  */
-Map_psvbfx *map_psvbfx_deserialize_bin(const char *file);
+int32_t map_psvbfx_deserialize_bin(const char *file, Map_psvbfx **out, char *error, uintptr_t error_len);
 
 /**
  * The returned instance has to be freed manually with the according `*_free` function or indirecly with another function that consumes and frees it.
@@ -600,27 +624,35 @@ void map_psbvfx_free(Map_psbvfx *x);
 
 /**
  * Serialize into json.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  */
-void map_psbvfx_serialize(const Map_psbvfx *x, const char *file);
+int32_t map_psbvfx_serialize(const Map_psbvfx *x, const char *file, char *error, uintptr_t error_len);
 
 /**
- * Deserialize from json.
+ * Deserialize from json. On success, the deserialized instance is written into `out`.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  *
  * The returned instance has to be freed manually with the according `*_free` function or indirecly with another function that consumes and frees it.
  */
-Map_psbvfx *map_psbvfx_deserialize(const char *file);
+int32_t map_psbvfx_deserialize(const char *file, Map_psbvfx **out, char *error, uintptr_t error_len);
 
 /**
  * Serialize into binary code.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  */
-void map_psbvfx_serialize_bin(const Map_psbvfx *x, const char *file);
+int32_t map_psbvfx_serialize_bin(const Map_psbvfx *x, const char *file, char *error, uintptr_t error_len);
 
 /**
- * Deserialize from binary codeh.
+ * Deserialize from binary code. On success, the deserialized instance is written into `out`.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  *
  * The returned instance has to be freed manually with the according `*_free` function or indirecly with another function that consumes and frees it.
  */
-Map_psbvfx *map_psbvfx_deserialize_bin(const char *file);
+int32_t map_psbvfx_deserialize_bin(const char *file, Map_psbvfx **out, char *error, uintptr_t error_len);
 
 /**
  * The returned instance has to be freed manually with the according `*_free` function or indirecly with another function that consumes and frees it.
@@ -647,27 +679,35 @@ void map_pefx_free(Map_pefx *x);
 
 /**
  * Serialize into json.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  */
-void map_pefx_serialize(const Map_pefx *x, const char *file);
+int32_t map_pefx_serialize(const Map_pefx *x, const char *file, char *error, uintptr_t error_len);
 
 /**
- * Deserialize from json.
+ * Deserialize from json. On success, the deserialized instance is written into `out`.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  *
  * The returned instance has to be freed manually with the according `*_free` function or indirecly with another function that consumes and frees it.
  */
-Map_pefx *map_pefx_deserialize(const char *file);
+int32_t map_pefx_deserialize(const char *file, Map_pefx **out, char *error, uintptr_t error_len);
 
 /**
  * Serialize into binary code.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  */
-void map_pefx_serialize_bin(const Map_pefx *x, const char *file);
+int32_t map_pefx_serialize_bin(const Map_pefx *x, const char *file, char *error, uintptr_t error_len);
 
 /**
- * Deserialize from binary codeh.
+ * Deserialize from binary code. On success, the deserialized instance is written into `out`.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  *
  * The returned instance has to be freed manually with the according `*_free` function or indirecly with another function that consumes and frees it.
  */
-Map_pefx *map_pefx_deserialize_bin(const char *file);
+int32_t map_pefx_deserialize_bin(const char *file, Map_pefx **out, char *error, uintptr_t error_len);
 
 /**
  * The returned instance has to be freed manually with the according `*_free` function or indirecly with another function that consumes and frees it.
@@ -694,27 +734,35 @@ void map_ptfx_free(Map_ptfx *x);
 
 /**
  * Serialize into json.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  */
-void map_ptfx_serialize(const Map_ptfx *x, const char *file);
+int32_t map_ptfx_serialize(const Map_ptfx *x, const char *file, char *error, uintptr_t error_len);
 
 /**
- * Deserialize from json.
+ * Deserialize from json. On success, the deserialized instance is written into `out`.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  *
  * The returned instance has to be freed manually with the according `*_free` function or indirecly with another function that consumes and frees it.
  */
-Map_ptfx *map_ptfx_deserialize(const char *file);
+int32_t map_ptfx_deserialize(const char *file, Map_ptfx **out, char *error, uintptr_t error_len);
 
 /**
  * Serialize into binary code.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  */
-void map_ptfx_serialize_bin(const Map_ptfx *x, const char *file);
+int32_t map_ptfx_serialize_bin(const Map_ptfx *x, const char *file, char *error, uintptr_t error_len);
 
 /**
- * Deserialize from binary codeh.
+ * Deserialize from binary code. On success, the deserialized instance is written into `out`.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  *
  * The returned instance has to be freed manually with the according `*_free` function or indirecly with another function that consumes and frees it.
  */
-Map_ptfx *map_ptfx_deserialize_bin(const char *file);
+int32_t map_ptfx_deserialize_bin(const char *file, Map_ptfx **out, char *error, uintptr_t error_len);
 
 /**
  * The returned instance has to be freed manually with the according `*_free` function or indirecly with another function that consumes and frees it.
@@ -741,27 +789,35 @@ void mapped_vector_psvbfx_free(MappedVector_psvbfx *x);
 
 /**
  * Serialize into json.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  */
-void mapped_vector_psvbfx_serialize(const MappedVector_psvbfx *x, const char *file);
+int32_t mapped_vector_psvbfx_serialize(const MappedVector_psvbfx *x, const char *file, char *error, uintptr_t error_len);
 
 /**
- * Deserialize from json.
+ * Deserialize from json. On success, the deserialized instance is written into `out`.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  *
  * The returned instance has to be freed manually with the according `*_free` function or indirecly with another function that consumes and frees it.
  */
-MappedVector_psvbfx *mapped_vector_psvbfx_deserialize(const char *file);
+int32_t mapped_vector_psvbfx_deserialize(const char *file, MappedVector_psvbfx **out, char *error, uintptr_t error_len);
 
 /**
  * Serialize into binary code.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  */
-void mapped_vector_psvbfx_serialize_bin(const MappedVector_psvbfx *x, const char *file);
+int32_t mapped_vector_psvbfx_serialize_bin(const MappedVector_psvbfx *x, const char *file, char *error, uintptr_t error_len);
 
 /**
- * Deserialize from binary codeh.
+ * Deserialize from binary code. On success, the deserialized instance is written into `out`.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  *
  * The returned instance has to be freed manually with the according `*_free` function or indirecly with another function that consumes and frees it.
  */
-MappedVector_psvbfx *mapped_vector_psvbfx_deserialize_bin(const char *file);
+int32_t mapped_vector_psvbfx_deserialize_bin(const char *file, MappedVector_psvbfx **out, char *error, uintptr_t error_len);
 
 /**
  * The returned instance has to be freed manually with the according `*_free` function or indirecly with another function that consumes and frees it.
@@ -788,27 +844,35 @@ void mapped_vector_psbvfx_free(MappedVector_psbvfx *x);
 
 /**
  * Serialize into json.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  */
-void mapped_vector_psbvfx_serialize(const MappedVector_psbvfx *x, const char *file);
+int32_t mapped_vector_psbvfx_serialize(const MappedVector_psbvfx *x, const char *file, char *error, uintptr_t error_len);
 
 /**
- * Deserialize from json.
+ * Deserialize from json. On success, the deserialized instance is written into `out`.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  *
  * The returned instance has to be freed manually with the according `*_free` function or indirecly with another function that consumes and frees it.
  */
-MappedVector_psbvfx *mapped_vector_psbvfx_deserialize(const char *file);
+int32_t mapped_vector_psbvfx_deserialize(const char *file, MappedVector_psbvfx **out, char *error, uintptr_t error_len);
 
 /**
  * Serialize into binary code.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  */
-void mapped_vector_psbvfx_serialize_bin(const MappedVector_psbvfx *x, const char *file);
+int32_t mapped_vector_psbvfx_serialize_bin(const MappedVector_psbvfx *x, const char *file, char *error, uintptr_t error_len);
 
 /**
- * Deserialize from binary codeh.
+ * Deserialize from binary code. On success, the deserialized instance is written into `out`.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  *
  * The returned instance has to be freed manually with the according `*_free` function or indirecly with another function that consumes and frees it.
  */
-MappedVector_psbvfx *mapped_vector_psbvfx_deserialize_bin(const char *file);
+int32_t mapped_vector_psbvfx_deserialize_bin(const char *file, MappedVector_psbvfx **out, char *error, uintptr_t error_len);
 
 /**
  * The returned instance has to be freed manually with the according `*_free` function or indirecly with another function that consumes and frees it.
@@ -835,27 +899,35 @@ void mapped_vector_pefx_free(MappedVector_pefx *x);
 
 /**
  * Serialize into json.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  */
-void mapped_vector_pefx_serialize(const MappedVector_pefx *x, const char *file);
+int32_t mapped_vector_pefx_serialize(const MappedVector_pefx *x, const char *file, char *error, uintptr_t error_len);
 
 /**
- * Deserialize from json.
+ * Deserialize from json. On success, the deserialized instance is written into `out`.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  *
  * The returned instance has to be freed manually with the according `*_free` function or indirecly with another function that consumes and frees it.
  */
-MappedVector_pefx *mapped_vector_pefx_deserialize(const char *file);
+int32_t mapped_vector_pefx_deserialize(const char *file, MappedVector_pefx **out, char *error, uintptr_t error_len);
 
 /**
  * Serialize into binary code.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  */
-void mapped_vector_pefx_serialize_bin(const MappedVector_pefx *x, const char *file);
+int32_t mapped_vector_pefx_serialize_bin(const MappedVector_pefx *x, const char *file, char *error, uintptr_t error_len);
 
 /**
- * Deserialize from binary codeh.
+ * Deserialize from binary code. On success, the deserialized instance is written into `out`.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  *
  * The returned instance has to be freed manually with the according `*_free` function or indirecly with another function that consumes and frees it.
  */
-MappedVector_pefx *mapped_vector_pefx_deserialize_bin(const char *file);
+int32_t mapped_vector_pefx_deserialize_bin(const char *file, MappedVector_pefx **out, char *error, uintptr_t error_len);
 
 /**
  * The returned instance has to be freed manually with the according `*_free` function or indirecly with another function that consumes and frees it.
@@ -882,27 +954,35 @@ void mapped_vector_ptfx_free(MappedVector_ptfx *x);
 
 /**
  * Serialize into json.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  */
-void mapped_vector_ptfx_serialize(const MappedVector_ptfx *x, const char *file);
+int32_t mapped_vector_ptfx_serialize(const MappedVector_ptfx *x, const char *file, char *error, uintptr_t error_len);
 
 /**
- * Deserialize from json.
+ * Deserialize from json. On success, the deserialized instance is written into `out`.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  *
  * The returned instance has to be freed manually with the according `*_free` function or indirecly with another function that consumes and frees it.
  */
-MappedVector_ptfx *mapped_vector_ptfx_deserialize(const char *file);
+int32_t mapped_vector_ptfx_deserialize(const char *file, MappedVector_ptfx **out, char *error, uintptr_t error_len);
 
 /**
  * Serialize into binary code.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  */
-void mapped_vector_ptfx_serialize_bin(const MappedVector_ptfx *x, const char *file);
+int32_t mapped_vector_ptfx_serialize_bin(const MappedVector_ptfx *x, const char *file, char *error, uintptr_t error_len);
 
 /**
- * Deserialize from binary codeh.
+ * Deserialize from binary code. On success, the deserialized instance is written into `out`.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  *
  * The returned instance has to be freed manually with the according `*_free` function or indirecly with another function that consumes and frees it.
  */
-MappedVector_ptfx *mapped_vector_ptfx_deserialize_bin(const char *file);
+int32_t mapped_vector_ptfx_deserialize_bin(const char *file, MappedVector_ptfx **out, char *error, uintptr_t error_len);
 
 /**
  * The returned instance has to be freed manually with the according `*_free` function or indirecly with another function that consumes and frees it.
@@ -929,27 +1009,35 @@ void buffered_vector_psvb_free(BufferedVector_psvb *x);
 
 /**
  * Serialize into json.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  */
-void buffered_vector_psvb_serialize(const BufferedVector_psvb *x, const char *file);
+int32_t buffered_vector_psvb_serialize(const BufferedVector_psvb *x, const char *file, char *error, uintptr_t error_len);
 
 /**
- * Deserialize from json.
+ * Deserialize from json. On success, the deserialized instance is written into `out`.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  *
  * The returned instance has to be freed manually with the according `*_free` function or indirecly with another function that consumes and frees it.
  */
-BufferedVector_psvb *buffered_vector_psvb_deserialize(const char *file);
+int32_t buffered_vector_psvb_deserialize(const char *file, BufferedVector_psvb **out, char *error, uintptr_t error_len);
 
 /**
  * Serialize into binary code.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  */
-void buffered_vector_psvb_serialize_bin(const BufferedVector_psvb *x, const char *file);
+int32_t buffered_vector_psvb_serialize_bin(const BufferedVector_psvb *x, const char *file, char *error, uintptr_t error_len);
 
 /**
- * Deserialize from binary codeh.
+ * Deserialize from binary code. On success, the deserialized instance is written into `out`.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  *
  * The returned instance has to be freed manually with the according `*_free` function or indirecly with another function that consumes and frees it.
  */
-BufferedVector_psvb *buffered_vector_psvb_deserialize_bin(const char *file);
+int32_t buffered_vector_psvb_deserialize_bin(const char *file, BufferedVector_psvb **out, char *error, uintptr_t error_len);
 
 /**
  * The returned instance has to be freed manually with the according `*_free` function or indirecly with another function that consumes and frees it.
@@ -976,27 +1064,35 @@ void buffered_vector_psbv_free(BufferedVector_psbv *x);
 
 /**
  * Serialize into json.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  */
-void buffered_vector_psbv_serialize(const BufferedVector_psbv *x, const char *file);
+int32_t buffered_vector_psbv_serialize(const BufferedVector_psbv *x, const char *file, char *error, uintptr_t error_len);
 
 /**
- * Deserialize from json.
+ * Deserialize from json. On success, the deserialized instance is written into `out`.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  *
  * The returned instance has to be freed manually with the according `*_free` function or indirecly with another function that consumes and frees it.
  */
-BufferedVector_psbv *buffered_vector_psbv_deserialize(const char *file);
+int32_t buffered_vector_psbv_deserialize(const char *file, BufferedVector_psbv **out, char *error, uintptr_t error_len);
 
 /**
  * Serialize into binary code.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  */
-void buffered_vector_psbv_serialize_bin(const BufferedVector_psbv *x, const char *file);
+int32_t buffered_vector_psbv_serialize_bin(const BufferedVector_psbv *x, const char *file, char *error, uintptr_t error_len);
 
 /**
- * Deserialize from binary codeh.
+ * Deserialize from binary code. On success, the deserialized instance is written into `out`.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  *
  * The returned instance has to be freed manually with the according `*_free` function or indirecly with another function that consumes and frees it.
  */
-BufferedVector_psbv *buffered_vector_psbv_deserialize_bin(const char *file);
+int32_t buffered_vector_psbv_deserialize_bin(const char *file, BufferedVector_psbv **out, char *error, uintptr_t error_len);
 
 /**
  * The returned instance has to be freed manually with the according `*_free` function or indirecly with another function that consumes and frees it.
@@ -1023,27 +1119,35 @@ void buffered_vector_pe_free(BufferedVector_pe *x);
 
 /**
  * Serialize into json.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  */
-void buffered_vector_pe_serialize(const BufferedVector_pe *x, const char *file);
+int32_t buffered_vector_pe_serialize(const BufferedVector_pe *x, const char *file, char *error, uintptr_t error_len);
 
 /**
- * Deserialize from json.
+ * Deserialize from json. On success, the deserialized instance is written into `out`.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  *
  * The returned instance has to be freed manually with the according `*_free` function or indirecly with another function that consumes and frees it.
  */
-BufferedVector_pe *buffered_vector_pe_deserialize(const char *file);
+int32_t buffered_vector_pe_deserialize(const char *file, BufferedVector_pe **out, char *error, uintptr_t error_len);
 
 /**
  * Serialize into binary code.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  */
-void buffered_vector_pe_serialize_bin(const BufferedVector_pe *x, const char *file);
+int32_t buffered_vector_pe_serialize_bin(const BufferedVector_pe *x, const char *file, char *error, uintptr_t error_len);
 
 /**
- * Deserialize from binary codeh.
+ * Deserialize from binary code. On success, the deserialized instance is written into `out`.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  *
  * The returned instance has to be freed manually with the according `*_free` function or indirecly with another function that consumes and frees it.
  */
-BufferedVector_pe *buffered_vector_pe_deserialize_bin(const char *file);
+int32_t buffered_vector_pe_deserialize_bin(const char *file, BufferedVector_pe **out, char *error, uintptr_t error_len);
 
 /**
  * The returned instance has to be freed manually with the according `*_free` function or indirecly with another function that consumes and frees it.
@@ -1070,27 +1174,35 @@ void buffered_vector_pt_free(BufferedVector_pt *x);
 
 /**
  * Serialize into json.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  */
-void buffered_vector_pt_serialize(const BufferedVector_pt *x, const char *file);
+int32_t buffered_vector_pt_serialize(const BufferedVector_pt *x, const char *file, char *error, uintptr_t error_len);
 
 /**
- * Deserialize from json.
+ * Deserialize from json. On success, the deserialized instance is written into `out`.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  *
  * The returned instance has to be freed manually with the according `*_free` function or indirecly with another function that consumes and frees it.
  */
-BufferedVector_pt *buffered_vector_pt_deserialize(const char *file);
+int32_t buffered_vector_pt_deserialize(const char *file, BufferedVector_pt **out, char *error, uintptr_t error_len);
 
 /**
  * Serialize into binary code.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  */
-void buffered_vector_pt_serialize_bin(const BufferedVector_pt *x, const char *file);
+int32_t buffered_vector_pt_serialize_bin(const BufferedVector_pt *x, const char *file, char *error, uintptr_t error_len);
 
 /**
- * Deserialize from binary codeh.
+ * Deserialize from binary code. On success, the deserialized instance is written into `out`.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  *
  * The returned instance has to be freed manually with the according `*_free` function or indirecly with another function that consumes and frees it.
  */
-BufferedVector_pt *buffered_vector_pt_deserialize_bin(const char *file);
+int32_t buffered_vector_pt_deserialize_bin(const char *file, BufferedVector_pt **out, char *error, uintptr_t error_len);
 
 /**
  * The returned instance has to be freed manually with the according `*_free` function or indirecly with another function that consumes and frees it.
@@ -1129,30 +1241,44 @@ void pauli_stack_vb_free(PauliStack_vb *x);
 
 /**
  * Serialize into json.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  */
-void pauli_stack_vb_serialize(const PauliStack_vb *x, const char *file);
+int32_t pauli_stack_vb_serialize(const PauliStack_vb *x, const char *file, char *error, uintptr_t error_len);
 
 /**
- * Deserialize from json.
+ * Deserialize from json. On success, the deserialized instance is written into `out`.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  *
  * The returned instance has to be freed manually with the according `*_free` function or indirecly with another function that consumes and frees it.
  */
-PauliStack_vb *pauli_stack_vb_deserialize(const char *file);
+int32_t pauli_stack_vb_deserialize(const char *file, PauliStack_vb **out, char *error, uintptr_t error_len);
 
 /**
  * Serialize into binary code.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  */
-void pauli_stack_vb_serialize_bin(const PauliStack_vb *x, const char *file);
+int32_t pauli_stack_vb_serialize_bin(const PauliStack_vb *x, const char *file, char *error, uintptr_t error_len);
 
 /**
- * Deserialize from binary codeh.
+ * Deserialize from binary code. On success, the deserialized instance is written into `out`.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  *
  * The returned instance has to be freed manually with the according `*_free` function or indirecly with another function that consumes and frees it.
  */
-PauliStack_vb *pauli_stack_vb_deserialize_bin(const char *file);
+int32_t pauli_stack_vb_deserialize_bin(const char *file, PauliStack_vb **out, char *error, uintptr_t error_len);
 
+/**
+ * Get a pointer to the X component of the stack.
+ */
 Vec_b *pauli_stack_vb_x(PauliStack_vb *x);
 
+/**
+ * Get a pointer to the Z component of the stack.
+ */
 Vec_b *pauli_stack_vb_z(PauliStack_vb *x);
 
 /**
@@ -1167,30 +1293,44 @@ void pauli_stack_bv_free(PauliStack_bv *x);
 
 /**
  * Serialize into json.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  */
-void pauli_stack_bv_serialize(const PauliStack_bv *x, const char *file);
+int32_t pauli_stack_bv_serialize(const PauliStack_bv *x, const char *file, char *error, uintptr_t error_len);
 
 /**
- * Deserialize from json.
+ * Deserialize from json. On success, the deserialized instance is written into `out`.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  *
  * The returned instance has to be freed manually with the according `*_free` function or indirecly with another function that consumes and frees it.
  */
-PauliStack_bv *pauli_stack_bv_deserialize(const char *file);
+int32_t pauli_stack_bv_deserialize(const char *file, PauliStack_bv **out, char *error, uintptr_t error_len);
 
 /**
  * Serialize into binary code.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  */
-void pauli_stack_bv_serialize_bin(const PauliStack_bv *x, const char *file);
+int32_t pauli_stack_bv_serialize_bin(const PauliStack_bv *x, const char *file, char *error, uintptr_t error_len);
 
 /**
- * Deserialize from binary codeh.
+ * Deserialize from binary code. On success, the deserialized instance is written into `out`.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  *
  * The returned instance has to be freed manually with the according `*_free` function or indirecly with another function that consumes and frees it.
  */
-PauliStack_bv *pauli_stack_bv_deserialize_bin(const char *file);
+int32_t pauli_stack_bv_deserialize_bin(const char *file, PauliStack_bv **out, char *error, uintptr_t error_len);
 
+/**
+ * Get a pointer to the X component of the stack.
+ */
 struct BitVec *pauli_stack_bv_x(PauliStack_bv *x);
 
+/**
+ * Get a pointer to the Z component of the stack.
+ */
 struct BitVec *pauli_stack_bv_z(PauliStack_bv *x);
 
 /**
@@ -1210,27 +1350,35 @@ void frames_hmpsvbfx_free(Frames_hmpsvbfx *x);
 
 /**
  * Serialize into json.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  */
-void frames_hmpsvbfx_serialize(const Frames_hmpsvbfx *x, const char *file);
+int32_t frames_hmpsvbfx_serialize(const Frames_hmpsvbfx *x, const char *file, char *error, uintptr_t error_len);
 
 /**
- * Deserialize from json.
+ * Deserialize from json. On success, the deserialized instance is written into `out`.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  *
  * The returned instance has to be freed manually with the according `*_free` function or indirecly with another function that consumes and frees it.
  */
-Frames_hmpsvbfx *frames_hmpsvbfx_deserialize(const char *file);
+int32_t frames_hmpsvbfx_deserialize(const char *file, Frames_hmpsvbfx **out, char *error, uintptr_t error_len);
 
 /**
  * Serialize into binary code.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  */
-void frames_hmpsvbfx_serialize_bin(const Frames_hmpsvbfx *x, const char *file);
+int32_t frames_hmpsvbfx_serialize_bin(const Frames_hmpsvbfx *x, const char *file, char *error, uintptr_t error_len);
 
 /**
- * Deserialize from binary codeh.
+ * Deserialize from binary code. On success, the deserialized instance is written into `out`.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  *
  * The returned instance has to be freed manually with the according `*_free` function or indirecly with another function that consumes and frees it.
  */
-Frames_hmpsvbfx *frames_hmpsvbfx_deserialize_bin(const char *file);
+int32_t frames_hmpsvbfx_deserialize_bin(const char *file, Frames_hmpsvbfx **out, char *error, uintptr_t error_len);
 
 /**
  * The returned instance has to be freed manually with the according `*_free` function or indirecly with another function that consumes and frees it.
@@ -1343,27 +1491,35 @@ void frames_hmpsbvfx_free(Frames_hmpsbvfx *x);
 
 /**
  * Serialize into json.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  */
-void frames_hmpsbvfx_serialize(const Frames_hmpsbvfx *x, const char *file);
+int32_t frames_hmpsbvfx_serialize(const Frames_hmpsbvfx *x, const char *file, char *error, uintptr_t error_len);
 
 /**
- * Deserialize from json.
+ * Deserialize from json. On success, the deserialized instance is written into `out`.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  *
  * The returned instance has to be freed manually with the according `*_free` function or indirecly with another function that consumes and frees it.
  */
-Frames_hmpsbvfx *frames_hmpsbvfx_deserialize(const char *file);
+int32_t frames_hmpsbvfx_deserialize(const char *file, Frames_hmpsbvfx **out, char *error, uintptr_t error_len);
 
 /**
  * Serialize into binary code.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  */
-void frames_hmpsbvfx_serialize_bin(const Frames_hmpsbvfx *x, const char *file);
+int32_t frames_hmpsbvfx_serialize_bin(const Frames_hmpsbvfx *x, const char *file, char *error, uintptr_t error_len);
 
 /**
- * Deserialize from binary codeh.
+ * Deserialize from binary code. On success, the deserialized instance is written into `out`.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  *
  * The returned instance has to be freed manually with the according `*_free` function or indirecly with another function that consumes and frees it.
  */
-Frames_hmpsbvfx *frames_hmpsbvfx_deserialize_bin(const char *file);
+int32_t frames_hmpsbvfx_deserialize_bin(const char *file, Frames_hmpsbvfx **out, char *error, uintptr_t error_len);
 
 /**
  * The returned instance has to be freed manually with the according `*_free` function or indirecly with another function that consumes and frees it.
@@ -1476,27 +1632,35 @@ void frames_bvpsvb_free(Frames_bvpsvb *x);
 
 /**
  * Serialize into json.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  */
-void frames_bvpsvb_serialize(const Frames_bvpsvb *x, const char *file);
+int32_t frames_bvpsvb_serialize(const Frames_bvpsvb *x, const char *file, char *error, uintptr_t error_len);
 
 /**
- * Deserialize from json.
+ * Deserialize from json. On success, the deserialized instance is written into `out`.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  *
  * The returned instance has to be freed manually with the according `*_free` function or indirecly with another function that consumes and frees it.
  */
-Frames_bvpsvb *frames_bvpsvb_deserialize(const char *file);
+int32_t frames_bvpsvb_deserialize(const char *file, Frames_bvpsvb **out, char *error, uintptr_t error_len);
 
 /**
  * Serialize into binary code.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  */
-void frames_bvpsvb_serialize_bin(const Frames_bvpsvb *x, const char *file);
+int32_t frames_bvpsvb_serialize_bin(const Frames_bvpsvb *x, const char *file, char *error, uintptr_t error_len);
 
 /**
- * Deserialize from binary codeh.
+ * Deserialize from binary code. On success, the deserialized instance is written into `out`.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  *
  * The returned instance has to be freed manually with the according `*_free` function or indirecly with another function that consumes and frees it.
  */
-Frames_bvpsvb *frames_bvpsvb_deserialize_bin(const char *file);
+int32_t frames_bvpsvb_deserialize_bin(const char *file, Frames_bvpsvb **out, char *error, uintptr_t error_len);
 
 /**
  * The returned instance has to be freed manually with the according `*_free` function or indirecly with another function that consumes and frees it.
@@ -1609,27 +1773,35 @@ void frames_bvpsbv_free(Frames_bvpsbv *x);
 
 /**
  * Serialize into json.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  */
-void frames_bvpsbv_serialize(const Frames_bvpsbv *x, const char *file);
+int32_t frames_bvpsbv_serialize(const Frames_bvpsbv *x, const char *file, char *error, uintptr_t error_len);
 
 /**
- * Deserialize from json.
+ * Deserialize from json. On success, the deserialized instance is written into `out`.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  *
  * The returned instance has to be freed manually with the according `*_free` function or indirecly with another function that consumes and frees it.
  */
-Frames_bvpsbv *frames_bvpsbv_deserialize(const char *file);
+int32_t frames_bvpsbv_deserialize(const char *file, Frames_bvpsbv **out, char *error, uintptr_t error_len);
 
 /**
  * Serialize into binary code.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  */
-void frames_bvpsbv_serialize_bin(const Frames_bvpsbv *x, const char *file);
+int32_t frames_bvpsbv_serialize_bin(const Frames_bvpsbv *x, const char *file, char *error, uintptr_t error_len);
 
 /**
- * Deserialize from binary codeh.
+ * Deserialize from binary code. On success, the deserialized instance is written into `out`.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  *
  * The returned instance has to be freed manually with the according `*_free` function or indirecly with another function that consumes and frees it.
  */
-Frames_bvpsbv *frames_bvpsbv_deserialize_bin(const char *file);
+int32_t frames_bvpsbv_deserialize_bin(const char *file, Frames_bvpsbv **out, char *error, uintptr_t error_len);
 
 /**
  * The returned instance has to be freed manually with the according `*_free` function or indirecly with another function that consumes and frees it.
@@ -1742,27 +1914,35 @@ void frames_mvpsvb_free(Frames_mvpsvbfx *x);
 
 /**
  * Serialize into json.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  */
-void frames_mvpsvb_serialize(const Frames_mvpsvbfx *x, const char *file);
+int32_t frames_mvpsvb_serialize(const Frames_mvpsvbfx *x, const char *file, char *error, uintptr_t error_len);
 
 /**
- * Deserialize from json.
+ * Deserialize from json. On success, the deserialized instance is written into `out`.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  *
  * The returned instance has to be freed manually with the according `*_free` function or indirecly with another function that consumes and frees it.
  */
-Frames_mvpsvbfx *frames_mvpsvb_deserialize(const char *file);
+int32_t frames_mvpsvb_deserialize(const char *file, Frames_mvpsvbfx **out, char *error, uintptr_t error_len);
 
 /**
  * Serialize into binary code.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  */
-void frames_mvpsvb_serialize_bin(const Frames_mvpsvbfx *x, const char *file);
+int32_t frames_mvpsvb_serialize_bin(const Frames_mvpsvbfx *x, const char *file, char *error, uintptr_t error_len);
 
 /**
- * Deserialize from binary codeh.
+ * Deserialize from binary code. On success, the deserialized instance is written into `out`.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  *
  * The returned instance has to be freed manually with the according `*_free` function or indirecly with another function that consumes and frees it.
  */
-Frames_mvpsvbfx *frames_mvpsvb_deserialize_bin(const char *file);
+int32_t frames_mvpsvb_deserialize_bin(const char *file, Frames_mvpsvbfx **out, char *error, uintptr_t error_len);
 
 /**
  * The returned instance has to be freed manually with the according `*_free` function or indirecly with another function that consumes and frees it.
@@ -1875,27 +2055,35 @@ void frames_mvpsbv_free(Frames_mvpsbvfx *x);
 
 /**
  * Serialize into json.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  */
-void frames_mvpsbv_serialize(const Frames_mvpsbvfx *x, const char *file);
+int32_t frames_mvpsbv_serialize(const Frames_mvpsbvfx *x, const char *file, char *error, uintptr_t error_len);
 
 /**
- * Deserialize from json.
+ * Deserialize from json. On success, the deserialized instance is written into `out`.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  *
  * The returned instance has to be freed manually with the according `*_free` function or indirecly with another function that consumes and frees it.
  */
-Frames_mvpsbvfx *frames_mvpsbv_deserialize(const char *file);
+int32_t frames_mvpsbv_deserialize(const char *file, Frames_mvpsbvfx **out, char *error, uintptr_t error_len);
 
 /**
  * Serialize into binary code.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  */
-void frames_mvpsbv_serialize_bin(const Frames_mvpsbvfx *x, const char *file);
+int32_t frames_mvpsbv_serialize_bin(const Frames_mvpsbvfx *x, const char *file, char *error, uintptr_t error_len);
 
 /**
- * Deserialize from binary codeh.
+ * Deserialize from binary code. On success, the deserialized instance is written into `out`.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  *
  * The returned instance has to be freed manually with the according `*_free` function or indirecly with another function that consumes and frees it.
  */
-Frames_mvpsbvfx *frames_mvpsbv_deserialize_bin(const char *file);
+int32_t frames_mvpsbv_deserialize_bin(const char *file, Frames_mvpsbvfx **out, char *error, uintptr_t error_len);
 
 /**
  * The returned instance has to be freed manually with the according `*_free` function or indirecly with another function that consumes and frees it.
@@ -2122,27 +2310,35 @@ void live_hmpefx_free(Live_hmpefx *x);
 
 /**
  * Serialize into json.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  */
-void live_hmpefx_serialize(const Live_hmpefx *x, const char *file);
+int32_t live_hmpefx_serialize(const Live_hmpefx *x, const char *file, char *error, uintptr_t error_len);
 
 /**
- * Deserialize from json.
+ * Deserialize from json. On success, the deserialized instance is written into `out`.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  *
  * The returned instance has to be freed manually with the according `*_free` function or indirecly with another function that consumes and frees it.
  */
-Live_hmpefx *live_hmpefx_deserialize(const char *file);
+int32_t live_hmpefx_deserialize(const char *file, Live_hmpefx **out, char *error, uintptr_t error_len);
 
 /**
  * Serialize into binary code.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  */
-void live_hmpefx_serialize_bin(const Live_hmpefx *x, const char *file);
+int32_t live_hmpefx_serialize_bin(const Live_hmpefx *x, const char *file, char *error, uintptr_t error_len);
 
 /**
- * Deserialize from binary codeh.
+ * Deserialize from binary code. On success, the deserialized instance is written into `out`.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  *
  * The returned instance has to be freed manually with the according `*_free` function or indirecly with another function that consumes and frees it.
  */
-Live_hmpefx *live_hmpefx_deserialize_bin(const char *file);
+int32_t live_hmpefx_deserialize_bin(const char *file, Live_hmpefx **out, char *error, uintptr_t error_len);
 
 /**
  * The returned instance has to be freed manually with the according `*_free` function or indirecly with another function that consumes and frees it.
@@ -2235,27 +2431,35 @@ void live_bvpe_free(Live_bvpe *x);
 
 /**
  * Serialize into json.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  */
-void live_bvpe_serialize(const Live_bvpe *x, const char *file);
+int32_t live_bvpe_serialize(const Live_bvpe *x, const char *file, char *error, uintptr_t error_len);
 
 /**
- * Deserialize from json.
+ * Deserialize from json. On success, the deserialized instance is written into `out`.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  *
  * The returned instance has to be freed manually with the according `*_free` function or indirecly with another function that consumes and frees it.
  */
-Live_bvpe *live_bvpe_deserialize(const char *file);
+int32_t live_bvpe_deserialize(const char *file, Live_bvpe **out, char *error, uintptr_t error_len);
 
 /**
  * Serialize into binary code.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  */
-void live_bvpe_serialize_bin(const Live_bvpe *x, const char *file);
+int32_t live_bvpe_serialize_bin(const Live_bvpe *x, const char *file, char *error, uintptr_t error_len);
 
 /**
- * Deserialize from binary codeh.
+ * Deserialize from binary code. On success, the deserialized instance is written into `out`.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  *
  * The returned instance has to be freed manually with the according `*_free` function or indirecly with another function that consumes and frees it.
  */
-Live_bvpe *live_bvpe_deserialize_bin(const char *file);
+int32_t live_bvpe_deserialize_bin(const char *file, Live_bvpe **out, char *error, uintptr_t error_len);
 
 /**
  * The returned instance has to be freed manually with the according `*_free` function or indirecly with another function that consumes and frees it.
@@ -2348,27 +2552,35 @@ void live_bvpt_free(Live_bvpt *x);
 
 /**
  * Serialize into json.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  */
-void live_bvpt_serialize(const Live_bvpt *x, const char *file);
+int32_t live_bvpt_serialize(const Live_bvpt *x, const char *file, char *error, uintptr_t error_len);
 
 /**
- * Deserialize from json.
+ * Deserialize from json. On success, the deserialized instance is written into `out`.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  *
  * The returned instance has to be freed manually with the according `*_free` function or indirecly with another function that consumes and frees it.
  */
-Live_bvpt *live_bvpt_deserialize(const char *file);
+int32_t live_bvpt_deserialize(const char *file, Live_bvpt **out, char *error, uintptr_t error_len);
 
 /**
  * Serialize into binary code.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  */
-void live_bvpt_serialize_bin(const Live_bvpt *x, const char *file);
+int32_t live_bvpt_serialize_bin(const Live_bvpt *x, const char *file, char *error, uintptr_t error_len);
 
 /**
- * Deserialize from binary codeh.
+ * Deserialize from binary code. On success, the deserialized instance is written into `out`.
+ *
+ * Returns 0 on success. Otherwise, it returns 1 if the file name is not valid UTF-8, 2 on IO errors, or 3 on (de)serialization errors; in this case, if `error` is not null, an error message is written into `error`, truncated to `error_len - 1` bytes and null-terminated.
  *
  * The returned instance has to be freed manually with the according `*_free` function or indirecly with another function that consumes and frees it.
  */
-Live_bvpt *live_bvpt_deserialize_bin(const char *file);
+int32_t live_bvpt_deserialize_bin(const char *file, Live_bvpt **out, char *error, uintptr_t error_len);
 
 /**
  * The returned instance has to be freed manually with the according `*_free` function or indirecly with another function that consumes and frees it.
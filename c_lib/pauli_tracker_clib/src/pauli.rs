@@ -27,3 +27,19 @@ boilerplate_stack! {
     (PauliStack_vb, pauli_stack_vb_, Vec_b),
     (PauliStack_bv, pauli_stack_bv_, BitVec),
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stack_accessors() {
+        let mut stack = PauliStack_vb::try_from_str("10", "01").unwrap();
+        assert_eq!(unsafe { &*pauli_stack_vb_z(&mut stack) }, &vec![true, false]);
+        assert_eq!(unsafe { &*pauli_stack_vb_x(&mut stack) }, &vec![false, true]);
+
+        let mut stack = PauliStack_bv::try_from_str("10", "01").unwrap();
+        assert!(unsafe { &*pauli_stack_bv_z(&mut stack) }[0]);
+        assert!(unsafe { &*pauli_stack_bv_x(&mut stack) }[1]);
+    }
+}
@@ -0,0 +1,37 @@
+// branchless (PauliDense) vs match-based (PauliEnum) conjugations of single Paulis
+
+use criterion::{Criterion, black_box, criterion_group, criterion_main};
+use pauli_tracker::pauli::{Pauli, PauliDense, PauliEnum};
+
+const NUM_PAULIS: usize = 10000;
+
+fn conjugate<P: Pauli + Copy>(paulis: &mut [P]) {
+    for (i, pauli) in paulis.iter_mut().enumerate() {
+        match i % 3 {
+            0 => pauli.s(),
+            1 => pauli.h(),
+            _ => pauli.shs(),
+        }
+    }
+}
+
+// irregular inputs, so that the branch predictor cannot simply learn the matches of
+// the enum representation
+fn paulis<P: Pauli + Copy>() -> Vec<P> {
+    let basis = [P::I, P::Z, P::X, P::Y];
+    (0..NUM_PAULIS).map(|i| basis[(i * 7 + i / 5) % 4]).collect()
+}
+
+pub fn criterion_benchmark(c: &mut Criterion) {
+    let mut dense = paulis::<PauliDense>();
+    c.bench_function("conjugation PauliDense", |b| {
+        b.iter(|| conjugate(black_box(&mut dense)))
+    });
+    let mut enumlike = paulis::<PauliEnum>();
+    c.bench_function("conjugation PauliEnum", |b| {
+        b.iter(|| conjugate(black_box(&mut enumlike)))
+    });
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
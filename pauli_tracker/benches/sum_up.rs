@@ -0,0 +1,52 @@
+// the vectorized sum_up of SimdBitVec vs the scalar loop over the frames
+
+use criterion::{Criterion, black_box, criterion_group, criterion_main};
+use pauli_tracker::{
+    boolean_vector::{BooleanVector, bitvec_simd::SimdBitVec},
+    pauli::{Pauli, PauliStack, PauliTuple},
+};
+
+const NUM_FRAMES: usize = 10000;
+
+// the default implementation of BooleanVector::sum_up
+fn scalar_sum_up<B: BooleanVector>(vec: &B, filter: &[bool]) -> bool {
+    vec.iter_vals()
+        .enumerate()
+        .filter_map(|(i, f)| if filter[i] { Some(f) } else { None })
+        .fold(false, |acc, next| acc ^ next)
+}
+
+fn build_stack<B: BooleanVector>() -> PauliStack<B> {
+    (0..NUM_FRAMES)
+        .map(|i| {
+            PauliTuple::new_product((i * 7 + i / 3) % 3 == 0, (i * 5 + i / 7) % 2 == 0)
+        })
+        .collect()
+}
+
+pub fn criterion_benchmark(c: &mut Criterion) {
+    let measurements =
+        (0..NUM_FRAMES).map(|i| (i * 3 + i / 11) % 4 < 2).collect::<Vec<_>>();
+
+    let stack = build_stack::<SimdBitVec>();
+    c.bench_function("sum_up SimdBitVec vectorized", |b| {
+        b.iter(|| black_box(&stack).sum_up(black_box(&measurements)))
+    });
+    c.bench_function("sum_up SimdBitVec scalar", |b| {
+        b.iter(|| {
+            let stack = black_box(&stack);
+            let measurements = black_box(&measurements);
+            PauliTuple::new_product(
+                scalar_sum_up(&stack.z, measurements),
+                scalar_sum_up(&stack.x, measurements),
+            )
+        })
+    });
+    let stack = build_stack::<Vec<bool>>();
+    c.bench_function("sum_up Vec<bool>", |b| {
+        b.iter(|| black_box(&stack).sum_up(black_box(&measurements)))
+    });
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
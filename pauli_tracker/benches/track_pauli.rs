@@ -0,0 +1,60 @@
+// tracking Paulis on a dense Frames tracker: the fast path for contiguous storages (cf.
+// Iterable::for_each_value_mut) vs. the generic loop over iter_pairs_mut
+
+use criterion::{BatchSize, Criterion, black_box, criterion_group, criterion_main};
+use pauli_tracker::{
+    collection::{BufferedVector, Init, Iterable},
+    pauli::{Pauli, PauliStack, PauliTuple},
+    tracker::{Tracker, frames::Frames},
+};
+
+const NUM_BITS: usize = 1000;
+const NUM_FRAMES: usize = 100;
+
+type Storage = BufferedVector<PauliStack<Vec<bool>>>;
+
+// the implementation of Frames::track_pauli before the fast path, modulo the frame
+// counter
+fn generic_track_pauli(storage: &mut Storage, qubit: usize, pauli: PauliTuple) {
+    for (i, p) in storage.iter_pairs_mut() {
+        if i == qubit {
+            p.push(pauli);
+        } else {
+            p.push(PauliTuple::new_i());
+        }
+    }
+}
+
+pub fn criterion_benchmark(c: &mut Criterion) {
+    c.bench_function("track_pauli fast path", |b| {
+        b.iter_batched(
+            || Frames::<Storage>::init(NUM_BITS),
+            |mut frames| {
+                for i in 0..NUM_FRAMES {
+                    frames.track_x(black_box((7 * i) % NUM_BITS));
+                }
+                frames
+            },
+            BatchSize::SmallInput,
+        )
+    });
+    c.bench_function("track_pauli generic", |b| {
+        b.iter_batched(
+            || Storage::init(NUM_BITS),
+            |mut storage| {
+                for i in 0..NUM_FRAMES {
+                    generic_track_pauli(
+                        &mut storage,
+                        black_box((7 * i) % NUM_BITS),
+                        PauliTuple::new_x(),
+                    );
+                }
+                storage
+            },
+            BatchSize::SmallInput,
+        )
+    });
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,38 @@
+// compare the serial and the parallel transpose of a big Frames tracker
+
+use criterion::{Criterion, black_box, criterion_group, criterion_main};
+use pauli_tracker::{
+    collection::{BufferedVector, Init},
+    pauli::{PauliStack, PauliTuple},
+    tracker::{Tracker, frames::Frames},
+};
+
+const NUM_BITS: usize = 200;
+const NUM_FRAMES: usize = 2000;
+
+type Storage = BufferedVector<PauliStack<Vec<bool>>>;
+
+fn tracker() -> Frames<Storage> {
+    let mut tracker = Frames::<Storage>::init(NUM_BITS);
+    // some deterministic "random-looking" circuit so that the frames are not too sparse
+    for i in 0..NUM_FRAMES {
+        tracker.track_x((7 * i) % NUM_BITS);
+        tracker.cx(i % NUM_BITS, (13 * i + 1) % NUM_BITS);
+        tracker.h((3 * i) % NUM_BITS);
+        tracker.cz((11 * i) % NUM_BITS, (5 * i + 1) % NUM_BITS);
+    }
+    tracker
+}
+
+pub fn criterion_benchmark(c: &mut Criterion) {
+    let tracker = tracker();
+    c.bench_function("transpose", |b| {
+        b.iter(|| black_box(&tracker).transpose::<PauliTuple>(NUM_BITS))
+    });
+    c.bench_function("par_transpose", |b| {
+        b.iter(|| black_box(&tracker).par_transpose::<PauliTuple>(NUM_BITS))
+    });
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
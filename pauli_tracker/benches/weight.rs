@@ -0,0 +1,56 @@
+// the weight of the frames of a big Frames tracker for different storage backends
+
+use criterion::{Criterion, black_box, criterion_group, criterion_main};
+use pauli_tracker::{
+    boolean_vector::BooleanVector,
+    collection::{BufferedVector, Init},
+    pauli::PauliStack,
+    tracker::{Tracker, frames::Frames},
+};
+
+const NUM_BITS: usize = 200;
+const NUM_FRAMES: usize = 2000;
+
+fn build_tracker<B: BooleanVector>() -> Frames<BufferedVector<PauliStack<B>>> {
+    let mut tracker = Frames::<BufferedVector<PauliStack<B>>>::init(NUM_BITS);
+    // some deterministic "random-looking" circuit so that the frames are not too sparse
+    for i in 0..NUM_FRAMES {
+        tracker.track_x((7 * i) % NUM_BITS);
+        tracker.cx(i % NUM_BITS, (13 * i + 1) % NUM_BITS);
+        tracker.h((3 * i) % NUM_BITS);
+        tracker.cz((11 * i) % NUM_BITS, (5 * i + 1) % NUM_BITS);
+    }
+    tracker
+}
+
+pub fn criterion_benchmark(c: &mut Criterion) {
+    let tracker = build_tracker::<Vec<bool>>();
+    c.bench_function("weight_per_frame Vec<bool>", |b| {
+        b.iter(|| black_box(&tracker).weight_per_frame())
+    });
+    #[cfg(feature = "bit-vec")]
+    {
+        let tracker = build_tracker::<bit_vec::BitVec>();
+        c.bench_function("weight_per_frame bit_vec::BitVec", |b| {
+            b.iter(|| black_box(&tracker).weight_per_frame())
+        });
+    }
+    #[cfg(feature = "bitvec")]
+    {
+        let tracker = build_tracker::<bitvec::vec::BitVec>();
+        c.bench_function("weight_per_frame bitvec::BitVec", |b| {
+            b.iter(|| black_box(&tracker).weight_per_frame())
+        });
+    }
+    #[cfg(feature = "bitvec_simd")]
+    {
+        let tracker =
+            build_tracker::<pauli_tracker::boolean_vector::bitvec_simd::SimdBitVec>();
+        c.bench_function("weight_per_frame SimdBitVec", |b| {
+            b.iter(|| black_box(&tracker).weight_per_frame())
+        });
+    }
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
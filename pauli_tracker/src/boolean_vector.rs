@@ -14,7 +14,7 @@ libraries too, for which it should be easy to implement [BooleanVector].
 [smallvec]: https://docs.rs/smallvec/1.10.0/smallvec/
 */
 
-use std::fmt::Debug;
+use std::{fmt::Debug, ops::Range};
 
 macro_rules! inplace {
     ($(($name:ident, $action:literal),)*) => {$(
@@ -25,7 +25,9 @@ macro_rules! inplace {
         /// # Panics
         /// Might panic if self.len() \neq rhs.len(). In general, if it does not panic, it
         /// probably applies the operation only on the elements of `self` maybe with some
-        /// padding values for `rhs`, depending on the implementation.
+        /// padding values for `rhs`, depending on the implementation. For a well-defined
+        /// behaviour on vectors with different lengths, compare
+        /// [xor_extend](BooleanVector::xor_extend).
         fn $name(&mut self, rhs: &Self);
     )*}
 }
@@ -59,6 +61,27 @@ pub trait BooleanVector:
     /// ```
     fn zeros(len: usize) -> Self;
 
+    /// Create a boolean vector with `len` many elements, where the elements at the
+    /// indices in `ones` are `true/1` and all other elements are `false/0`.
+    ///
+    /// # Panics
+    /// Panics if an index in `ones` is out of bounds, i.e., not smaller than `len`.
+    ///
+    /// # Examples
+    ///```
+    /// # fn main() { #![cfg_attr(coverage_nightly, coverage(off))]
+    /// use pauli_tracker::boolean_vector::BooleanVector;
+    /// assert_eq!(Vec::<bool>::from_ones(4, [0, 2]), vec![true, false, true, false])
+    /// # }
+    /// ```
+    fn from_ones(len: usize, ones: impl IntoIterator<Item = usize>) -> Self {
+        let mut ret = Self::zeros(len);
+        for idx in ones {
+            ret.set(idx, true);
+        }
+        ret
+    }
+
     /// Set the element at `idx` to `flag`.
     ///
     /// # Panics
@@ -80,6 +103,12 @@ pub trait BooleanVector:
     /// Resize the boolean vector to contain `len` elements, where new values are
     /// initialized with `flag`.
     ///
+    /// Shrinking truncates the vector but keeps its allocated capacity, so that growing
+    /// it again, e.g., when tracking new Paulis after a stack has been cleared with
+    /// `resize(0, false)` in the [move](crate::tracker::Tracker::move_z_to_z)
+    /// operations, does not have to reallocate. Growing after shrinking fills the new
+    /// elements with `flag`; it never exposes previously truncated values.
+    ///
     /// # Examples
     ///```
     /// # fn main() { #![cfg_attr(coverage_nightly, coverage(off))]
@@ -152,6 +181,183 @@ pub trait BooleanVector:
             .filter_map(|(i, f)| if filter[i] { Some(f) } else { None })
             .fold(false, |acc, next| acc ^ next)
     }
+
+    /// Count the number of `true` elements.
+    ///
+    /// The default implementation works elementwise; the implementations for
+    /// [bitvec::vec::BitVec], [bit_vec::BitVec] and the SIMD vector overwrite it with a
+    /// popcount over their storage blocks.
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() { #![cfg_attr(coverage_nightly, coverage(off))]
+    /// # use pauli_tracker::boolean_vector::BooleanVector;
+    /// let vec = vec![true, false, true, true];
+    /// assert_eq!(vec.count_ones(), 3);
+    /// # }
+    /// ```
+    fn count_ones(&self) -> usize {
+        self.iter_vals().filter(|flag| *flag).count()
+    }
+
+    /// Call `f` with the index of each `true` element, in ascending order.
+    ///
+    /// The default implementation works elementwise; the implementations for
+    /// [bitvec::vec::BitVec] and [bit_vec::BitVec] overwrite it to skip over blocks of
+    /// `false`s.
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() { #![cfg_attr(coverage_nightly, coverage(off))]
+    /// # use pauli_tracker::boolean_vector::BooleanVector;
+    /// let vec = vec![true, false, true, true];
+    /// let mut ones = Vec::new();
+    /// vec.for_each_one(|idx| ones.push(idx));
+    /// assert_eq!(ones, vec![0, 2, 3]);
+    /// # }
+    /// ```
+    fn for_each_one(&self, mut f: impl FnMut(usize)) {
+        for (idx, flag) in self.iter_vals().enumerate() {
+            if flag {
+                f(idx);
+            }
+        }
+    }
+
+    /// Return the approximate number of bytes that are allocated on the heap by the
+    /// vector, i.e., its capacity times the size of its storage blocks.
+    ///
+    /// The default implementation assumes densely packed bits without any spare
+    /// capacity, i.e., it returns the number of bytes that are needed to store
+    /// [len](Self::len) bits. The implementations for the foreign types overwrite it.
+    fn capacity_bytes(&self) -> usize {
+        (self.len() + 7) / 8
+    }
+
+    /// Reserve capacity for at least `additional` more bits, e.g., to avoid
+    /// reallocations when the number of bits that will be pushed is known in advance.
+    ///
+    /// The default implementation does nothing; the implementations for [Vec]<[bool]>,
+    /// [bitvec::vec::BitVec] and [bit_vec::BitVec] overwrite it.
+    fn reserve(&mut self, additional: usize) {
+        let _ = additional;
+    }
+
+    /// Perform an elementwise AND between `self` and `rhs`, updating `self`.
+    ///
+    /// The default implementation works elementwise; the implementations for
+    /// [Vec]<[bool]>, [bitvec::vec::BitVec] and [bit_vec::BitVec] overwrite it.
+    ///
+    /// # Panics
+    /// Might panic if self.len() \neq rhs.len(), compare
+    /// [xor_inplace](BooleanVector::xor_inplace).
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() { #![cfg_attr(coverage_nightly, coverage(off))]
+    /// # use pauli_tracker::boolean_vector::BooleanVector;
+    /// let mut vec = vec![true, false, true];
+    /// vec.and_inplace(&vec![true, true, false]);
+    /// assert_eq!(vec, vec![true, false, false]);
+    /// # }
+    /// ```
+    fn and_inplace(&mut self, rhs: &Self) {
+        let zeros = rhs
+            .iter_vals()
+            .enumerate()
+            .filter_map(|(idx, flag)| (!flag).then_some(idx))
+            .collect::<Vec<_>>();
+        for idx in zeros {
+            self.set(idx, false);
+        }
+    }
+
+    /// Perform an elementwise XOR between `self` and `rhs`, updating `self`, where the
+    /// shorter of the two vectors is implicitly padded with `false`s. This means that
+    /// afterwards `self` has the length of the longer one of the two vectors.
+    ///
+    /// In contrast to [xor_inplace](BooleanVector::xor_inplace), this is well-defined
+    /// for all implementors if the lengths differ, but it may be slower.
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() { #![cfg_attr(coverage_nightly, coverage(off))]
+    /// # use pauli_tracker::boolean_vector::BooleanVector;
+    /// let mut vec = vec![true, false];
+    /// vec.xor_extend(&vec![true, true, true]);
+    /// assert_eq!(vec, vec![false, true, true]);
+    /// vec.xor_extend(&vec![true]);
+    /// assert_eq!(vec, vec![true, true, true]);
+    /// # }
+    /// ```
+    fn xor_extend(&mut self, rhs: &Self) {
+        match self.len().cmp(&rhs.len()) {
+            std::cmp::Ordering::Less => {
+                self.resize(rhs.len(), false);
+                self.xor_inplace(rhs);
+            },
+            std::cmp::Ordering::Equal => self.xor_inplace(rhs),
+            std::cmp::Ordering::Greater => {
+                let mut rhs = rhs.clone();
+                rhs.resize(self.len(), false);
+                self.xor_inplace(&rhs);
+            },
+        }
+    }
+
+    /// Perform an elementwise XOR between `self` and `rhs` only on the elements in
+    /// `range`, updating `self`.
+    ///
+    /// The default implementation works elementwise; the implementations for
+    /// [Vec]<[bool]> and [bitvec::vec::BitVec] overwrite it.
+    ///
+    /// # Panics
+    /// Panics if `range` is out of bounds for `self` or `rhs`.
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() { #![cfg_attr(coverage_nightly, coverage(off))]
+    /// # use pauli_tracker::boolean_vector::BooleanVector;
+    /// let mut vec = vec![true, false, true, false];
+    /// vec.xor_range(&vec![true, true, true, true], 1..3);
+    /// assert_eq!(vec, vec![true, true, false, false]);
+    /// # }
+    /// ```
+    fn xor_range(&mut self, rhs: &Self, range: Range<usize>) {
+        for idx in range {
+            let flag = self.get(idx).expect("range out of bounds for self")
+                ^ rhs.get(idx).expect("range out of bounds for rhs");
+            self.set(idx, flag);
+        }
+    }
+
+    /// Swap the elements in `range` between `self` and `other`.
+    ///
+    /// The default implementation works elementwise; the implementations for
+    /// [Vec]<[bool]> and [bitvec::vec::BitVec] overwrite it.
+    ///
+    /// # Panics
+    /// Panics if `range` is out of bounds for `self` or `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() { #![cfg_attr(coverage_nightly, coverage(off))]
+    /// # use pauli_tracker::boolean_vector::BooleanVector;
+    /// let mut a = vec![true, true, true];
+    /// let mut b = vec![false, false, false];
+    /// a.swap_range(&mut b, 1..2);
+    /// assert_eq!(a, vec![true, false, true]);
+    /// assert_eq!(b, vec![false, true, false]);
+    /// # }
+    /// ```
+    fn swap_range(&mut self, other: &mut Self, range: Range<usize>) {
+        for idx in range {
+            let a = self.get(idx).expect("range out of bounds for self");
+            let b = other.get(idx).expect("range out of bounds for other");
+            self.set(idx, b);
+            other.set(idx, a);
+        }
+    }
 }
 
 mod std_vec;
@@ -171,6 +377,11 @@ mod bit_vec;
 #[cfg(test)]
 mod tests {
     use coverage_helper::test;
+    use proptest::{
+        collection::vec,
+        prelude::{Strategy, any},
+        proptest,
+    };
 
     use super::*;
 
@@ -179,4 +390,201 @@ mod tests {
         assert!(<Vec<bool> as BooleanVector>::is_empty(&vec![]));
         assert!(!<Vec<bool> as BooleanVector>::is_empty(&vec![true]));
     }
+
+    #[test]
+    fn from_ones() {
+        #[cfg_attr(coverage_nightly, coverage(off))]
+        fn check<B: BooleanVector + PartialEq>() {
+            let mut manual = B::zeros(0);
+            manual.resize(11, false);
+            for idx in [0, 3, 4, 10] {
+                manual.set(idx, true);
+            }
+            assert_eq!(B::from_ones(11, [10, 3, 0, 4]), manual);
+            assert_eq!(B::from_ones(3, []), B::zeros(3));
+        }
+        check::<Vec<bool>>();
+        check::<::bitvec::vec::BitVec>();
+        check::<::bit_vec::BitVec>();
+        check::<crate::boolean_vector::bitvec_simd::SimdBitVec>();
+    }
+
+    #[test]
+    fn resize() {
+        #[cfg_attr(coverage_nightly, coverage(off))]
+        fn check<B: BooleanVector>() {
+            let mut vec = B::from_ones(300, [0, 150, 299]);
+            vec.resize(100, false);
+            assert_eq!(vec.len(), 100);
+            assert_eq!(vec.iter_vals().filter(|&f| f).count(), 1);
+            // the truncated ones do not show up again
+            vec.resize(300, false);
+            assert_eq!(
+                vec.iter_vals().collect::<Vec<_>>(),
+                B::from_ones(300, [0]).iter_vals().collect::<Vec<_>>()
+            );
+            vec.resize(100, true);
+            vec.resize(310, true);
+            assert_eq!(vec.len(), 310);
+            assert!(!vec.get(1).unwrap());
+            assert!(vec.iter_vals().skip(100).all(|f| f));
+            vec.resize(0, true);
+            assert!(vec.is_empty());
+            vec.resize(5, false);
+            assert_eq!(vec.iter_vals().collect::<Vec<_>>(), vec![false; 5]);
+        }
+        check::<Vec<bool>>();
+        check::<::bitvec::vec::BitVec>();
+        check::<::bit_vec::BitVec>();
+        check::<crate::boolean_vector::bitvec_simd::SimdBitVec>();
+
+        // the foreign types report their actual capacity
+        #[cfg_attr(coverage_nightly, coverage(off))]
+        fn check_capacity<B: BooleanVector>() {
+            let mut vec = B::zeros(1000);
+            let capacity = vec.capacity_bytes();
+            vec.resize(0, false);
+            assert_eq!(vec.capacity_bytes(), capacity);
+            vec.resize(1000, true);
+            assert_eq!(vec.capacity_bytes(), capacity);
+        }
+        check_capacity::<Vec<bool>>();
+        check_capacity::<::bitvec::vec::BitVec>();
+        check_capacity::<::bit_vec::BitVec>();
+    }
+
+    #[test]
+    fn and_inplace() {
+        #[cfg_attr(coverage_nightly, coverage(off))]
+        fn check<B: BooleanVector>() {
+            let mut vec = B::from_ones(300, [0, 3, 150, 299]);
+            vec.and_inplace(&B::from_ones(300, [3, 4, 150, 200]));
+            assert_eq!(
+                vec.iter_vals().collect::<Vec<_>>(),
+                B::from_ones(300, [3, 150]).iter_vals().collect::<Vec<_>>()
+            );
+        }
+        check::<Vec<bool>>();
+        check::<::bitvec::vec::BitVec>();
+        check::<::bit_vec::BitVec>();
+        check::<crate::boolean_vector::bitvec_simd::SimdBitVec>();
+    }
+
+    proptest! {
+        #[test]
+        fn xor_extend(
+            lhs in vec(any::<bool>(), 0..200),
+            rhs in vec(any::<bool>(), 0..200),
+        ) {
+            let len = lhs.len().max(rhs.len());
+            let expected = (0..len)
+                .map(|i| {
+                    lhs.get(i).unwrap_or(false) ^ rhs.get(i).unwrap_or(false)
+                })
+                .collect::<Vec<_>>();
+
+            #[cfg_attr(coverage_nightly, coverage(off))]
+            fn check<B: BooleanVector>(lhs: &[bool], rhs: &[bool], expected: &[bool]) {
+                let mut vec = lhs.iter().copied().collect::<B>();
+                vec.xor_extend(&rhs.iter().copied().collect());
+                assert_eq!(vec.iter_vals().collect::<Vec<_>>(), expected);
+            }
+            check::<Vec<bool>>(&lhs, &rhs, &expected);
+            check::<::bitvec::vec::BitVec>(&lhs, &rhs, &expected);
+            check::<::bit_vec::BitVec>(&lhs, &rhs, &expected);
+            check::<crate::boolean_vector::bitvec_simd::SimdBitVec>(&lhs, &rhs, &expected);
+        }
+
+        #[test]
+        fn sum_up(
+            (vec, filter) in (0..600usize).prop_flat_map(|len| {
+                (vec(any::<bool>(), len), vec(any::<bool>(), len..len + 10))
+            }),
+        ) {
+            let expected = vec
+                .iter()
+                .zip(&filter)
+                .fold(false, |acc, (flag, filtered)| acc ^ (flag & filtered));
+
+            #[cfg_attr(coverage_nightly, coverage(off))]
+            fn check<B: BooleanVector>(vec: &[bool], filter: &[bool], expected: bool) {
+                let vec = vec.iter().copied().collect::<B>();
+                assert_eq!(vec.sum_up(filter), expected);
+            }
+            check::<Vec<bool>>(&vec, &filter, expected);
+            check::<::bitvec::vec::BitVec>(&vec, &filter, expected);
+            check::<::bit_vec::BitVec>(&vec, &filter, expected);
+            check::<crate::boolean_vector::bitvec_simd::SimdBitVec>(
+                &vec, &filter, expected,
+            );
+        }
+
+        #[test]
+        fn ones(vec in vec(any::<bool>(), 0..600), truncate in 0..600usize) {
+            let len = truncate.min(vec.len());
+            let expected = vec[..len]
+                .iter()
+                .enumerate()
+                .filter_map(|(idx, flag)| flag.then_some(idx))
+                .collect::<Vec<_>>();
+
+            #[cfg_attr(coverage_nightly, coverage(off))]
+            fn check<B: BooleanVector>(vec: &[bool], len: usize, expected: &[usize]) {
+                // truncate, so that there may be set bits in the unused storage
+                let mut vec = vec.iter().copied().collect::<B>();
+                vec.resize(len, false);
+                assert_eq!(vec.count_ones(), expected.len());
+                let mut ones = Vec::new();
+                vec.for_each_one(|idx| ones.push(idx));
+                assert_eq!(ones, expected);
+            }
+            check::<Vec<bool>>(&vec, len, &expected);
+            check::<::bitvec::vec::BitVec>(&vec, len, &expected);
+            check::<::bit_vec::BitVec>(&vec, len, &expected);
+            check::<crate::boolean_vector::bitvec_simd::SimdBitVec>(&vec, len, &expected);
+        }
+
+        #[test]
+        fn range_operations(
+            (lhs, rhs) in (0..200usize).prop_flat_map(|len| {
+                (vec(any::<bool>(), len), vec(any::<bool>(), len))
+            }),
+            a in 0..200usize,
+            b in 0..200usize,
+        ) {
+            let len = lhs.len();
+            let range = a.min(b).min(len)..a.max(b).min(len);
+            let mut xored = lhs.clone();
+            let (mut swapped_lhs, mut swapped_rhs) = (lhs.clone(), rhs.clone());
+            for idx in range.clone() {
+                xored[idx] ^= rhs[idx];
+                swapped_lhs[idx] = rhs[idx];
+                swapped_rhs[idx] = lhs[idx];
+            }
+
+            #[cfg_attr(coverage_nightly, coverage(off))]
+            fn check<B: BooleanVector>(
+                lhs: &[bool],
+                rhs: &[bool],
+                range: Range<usize>,
+                (xored, swapped_lhs, swapped_rhs): (&[bool], &[bool], &[bool]),
+            ) {
+                let mut vec = lhs.iter().copied().collect::<B>();
+                let mut other = rhs.iter().copied().collect::<B>();
+                vec.xor_range(&other, range.clone());
+                assert_eq!(vec.iter_vals().collect::<Vec<_>>(), xored);
+                let mut vec = lhs.iter().copied().collect::<B>();
+                vec.swap_range(&mut other, range);
+                assert_eq!(vec.iter_vals().collect::<Vec<_>>(), swapped_lhs);
+                assert_eq!(other.iter_vals().collect::<Vec<_>>(), swapped_rhs);
+            }
+            let expected = (&xored[..], &swapped_lhs[..], &swapped_rhs[..]);
+            check::<Vec<bool>>(&lhs, &rhs, range.clone(), expected);
+            check::<::bitvec::vec::BitVec>(&lhs, &rhs, range.clone(), expected);
+            check::<::bit_vec::BitVec>(&lhs, &rhs, range.clone(), expected);
+            check::<crate::boolean_vector::bitvec_simd::SimdBitVec>(
+                &lhs, &rhs, range, expected,
+            );
+        }
+    }
 }
@@ -30,6 +30,10 @@ impl BooleanVector for BitVec {
         self.or(rhs);
     }
 
+    fn and_inplace(&mut self, rhs: &Self) {
+        self.and(rhs);
+    }
+
     fn resize(&mut self, len: usize, flag: bool) {
         let current_len = self.len();
         match current_len.cmp(&len) {
@@ -63,4 +67,42 @@ impl BooleanVector for BitVec {
     fn iter_vals(&self) -> Self::IterVals<'_> {
         self.iter()
     }
+
+    fn count_ones(&self) -> usize {
+        // mask the unused bits of the last block, to not rely on them being zero
+        let full = self.len() / 32;
+        let rest = self.len() % 32;
+        let storage = self.storage();
+        let mut count =
+            storage[..full].iter().map(|block| block.count_ones() as usize).sum();
+        if rest != 0 {
+            count += (storage[full] & ((1 << rest) - 1)).count_ones() as usize;
+        }
+        count
+    }
+
+    fn for_each_one(&self, mut f: impl FnMut(usize)) {
+        let len = self.len();
+        for (block_idx, &block) in self.storage().iter().enumerate() {
+            let mut block = block;
+            while block != 0 {
+                let idx = block_idx * 32 + block.trailing_zeros() as usize;
+                if idx >= len {
+                    return;
+                }
+                f(idx);
+                // clear the lowest set bit
+                block &= block - 1;
+            }
+        }
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        BitVec::reserve(self, additional)
+    }
+
+    fn capacity_bytes(&self) -> usize {
+        // the capacity is a multiple of the block size (u32)
+        self.capacity() / 8
+    }
 }
@@ -1,3 +1,5 @@
+use std::ops::Range;
+
 use bitvec::{
     order::Lsb0,
     slice::{BitSlice, BitValIter},
@@ -33,6 +35,18 @@ impl<T: BitStore> BooleanVector for BitVec<T, Lsb0> {
         *self |= rhs;
     }
 
+    fn and_inplace(&mut self, rhs: &Self) {
+        *self &= rhs;
+    }
+
+    fn xor_range(&mut self, rhs: &Self, range: Range<usize>) {
+        self[range.clone()] ^= &rhs[range];
+    }
+
+    fn swap_range(&mut self, other: &mut Self, range: Range<usize>) {
+        self[range.clone()].swap_with_bitslice(&mut other[range]);
+    }
+
     fn resize(&mut self, len: usize, flag: bool) {
         self.resize(len, flag);
     }
@@ -56,4 +70,21 @@ impl<T: BitStore> BooleanVector for BitVec<T, Lsb0> {
     fn iter_vals(&self) -> Self::IterVals<'_> {
         BitSlice::iter(self).by_vals()
     }
+
+    fn count_ones(&self) -> usize {
+        BitSlice::count_ones(self)
+    }
+
+    fn for_each_one(&self, f: impl FnMut(usize)) {
+        self.iter_ones().for_each(f)
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        BitVec::reserve(self, additional)
+    }
+
+    fn capacity_bytes(&self) -> usize {
+        // the capacity is measured in bits
+        (self.capacity() + 7) / 8
+    }
 }
@@ -136,4 +136,61 @@ impl BooleanVector for SimdBitVec {
     fn iter_vals(&self) -> Self::IterVals<'_> {
         IterFromRef { vec: self, current: 0 }
     }
+
+    fn sum_up(&self, filter: &[bool]) -> bool {
+        // mask with the filter and take the parity of the popcount, instead of iterating
+        // over the bits
+        let mut mask = BitVec::from_bool_iterator(filter[..self.len()].iter().copied());
+        mask.and_inplace(&self.0);
+        mask.count_ones() % 2 == 1
+    }
+
+    fn count_ones(&self) -> usize {
+        self.0.count_ones()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use coverage_helper::test;
+
+    use super::*;
+
+    // the default implementation of BooleanVector::sum_up
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn scalar_sum_up(vec: &SimdBitVec, filter: &[bool]) -> bool {
+        vec.iter_vals()
+            .enumerate()
+            .filter_map(|(i, f)| if filter[i] { Some(f) } else { None })
+            .fold(false, |acc, next| acc ^ next)
+    }
+
+    #[test]
+    fn sum_up_against_scalar() {
+        // the vectors are stored in lanes of 256 bits; check lengths around the lane
+        // boundaries, in particular the ones that are not multiples of the lane width
+        let lengths = [0, 1, 7, 63, 64, 65, 127, 255, 256, 257, 300, 511, 512, 513, 1000];
+        // a cheap deterministic pseudo-random bit pattern
+        let mut state = 0x2545_f491_4f6c_dd1du64;
+        let mut next_bit = move || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state & 1 == 1
+        };
+        for len in lengths {
+            for _ in 0..10 {
+                let vec = (0..len).map(|_| next_bit()).collect::<SimdBitVec>();
+                // longer than the vector, so that the slicing of the filter is checked
+                let filter = (0..len + 10).map(|_| next_bit()).collect::<Vec<_>>();
+                assert_eq!(
+                    vec.sum_up(&filter),
+                    scalar_sum_up(&vec, &filter),
+                    "len {len}"
+                );
+                let ones = vec![true; len + 10];
+                assert_eq!(vec.sum_up(&ones), scalar_sum_up(&vec, &ones), "len {len}");
+            }
+        }
+    }
 }
@@ -1,4 +1,4 @@
-use std::{iter::Copied, slice::Iter};
+use std::{iter::Copied, mem, ops::Range, slice::Iter};
 
 use super::BooleanVector;
 
@@ -34,10 +34,35 @@ impl BooleanVector for Vec<bool> {
         }
     }
 
+    fn and_inplace(&mut self, rhs: &Self) {
+        check_len(self, rhs);
+        for (l, r) in self.iter_mut().zip(rhs) {
+            *l &= r;
+        }
+    }
+
+    fn xor_range(&mut self, rhs: &Self, range: Range<usize>) {
+        for (l, r) in self[range.clone()].iter_mut().zip(&rhs[range]) {
+            *l ^= r;
+        }
+    }
+
+    fn swap_range(&mut self, other: &mut Self, range: Range<usize>) {
+        self[range.clone()].swap_with_slice(&mut other[range]);
+    }
+
     fn resize(&mut self, len: usize, flag: bool) {
         self.resize(len, flag);
     }
 
+    fn reserve(&mut self, additional: usize) {
+        Vec::reserve(self, additional)
+    }
+
+    fn capacity_bytes(&self) -> usize {
+        self.capacity() * mem::size_of::<bool>()
+    }
+
     fn push(&mut self, flag: bool) {
         self.push(flag)
     }
@@ -5,19 +5,26 @@ The main content of this module is a wrapper [TrackedCircuit] that provides an m
 to track Paulis while building up the circuit or executing it. One can either use it
 directly by providing an appropriate circuit simulator (should implement
 [CliffordCircuit]) or use it as template/idea to write a custom wrapper. The module also
-provides two pseudo circuit simulators that can be used to test the Pauli tracking.
+provides three pseudo circuit simulators that can be used to test the Pauli tracking.
 */
 
-use std::mem;
+use std::{
+    f64::consts::{PI, TAU},
+    mem,
+};
 
 use crate::{
     boolean_vector::BooleanVector,
     clifford_helper,
-    collection::{Base, Full},
-    pauli::PauliStack,
+    collection::{Base, Full, Iterable, IterableBase},
+    pauli::{Pauli, PauliStack},
     tracker::{
-        PauliString, Tracker,
-        frames::{Frames, MoveError, OverwriteStack},
+        MissingBit, PauliString, Tracker,
+        frames::{
+            Frames, MoveError, OverwriteStack,
+            induced_order::{self, IncrementalOrder, PartialOrderGraph},
+        },
+        live::Live,
     },
 };
 
@@ -63,6 +70,12 @@ macro_rules! coset {
 /// We don't really care what the circuit is actually doing, except for possible
 /// measurement outcomes, since we only use this interface to pass the actions through
 /// to the implementing circuit.
+///
+/// Implementors only have to implement the generators [S](Self::s), [H](Self::h) and
+/// [CZ](Self::cz), and [measure](Self::measure); all other gates have default
+/// implementations in terms of the generators (up to phases, compare the [Tracker]
+/// documentation). Simulators that have direct implementations for some of the other
+/// gates should of course overwrite the default implementations.
 pub trait CliffordCircuit {
     /// The type of the measurement outcome, e.g., a boolean for
     /// [RandomMeasurementCircuit].
@@ -75,7 +88,7 @@ pub trait CliffordCircuit {
 }
 
 mod dummies;
-pub use dummies::{DummyCircuit, RandomMeasurementCircuit};
+pub use dummies::{DummyCircuit, RandomMeasurementCircuit, ReplayCircuit};
 
 /// A Wrapper around a Clifford circuit (simulator) and a Pauli tracker.
 ///
@@ -188,7 +201,7 @@ impl<C, T, S> TrackedCircuit<C, T, S>
 where
     T: Tracker,
 {
-    /// Append a [Pauli](crate::pauli::Pauli) gate `pauli` to the tracker.
+    /// Append a [Pauli] gate `pauli` to the tracker.
     pub fn track_pauli(&mut self, bit: usize, pauli: T::Pauli) {
         self.tracker.track_pauli(bit, pauli)
     }
@@ -238,6 +251,8 @@ where
         (sx, "SX"),
         (sxdg, "SXDG"),
         (hyz, "H_yz"),
+        (v, "V"),
+        (vdg, "VDG"),
     );
 
     double_gate!(cz, "Control Z");
@@ -249,6 +264,24 @@ where
     double_gate!(zcy, "Z-Control Y", control, target);
     double_gate!(iswap, "iSWAP");
     double_gate!(iswapdg, "iSWAP^dagger");
+
+    /// Prepare the graph state described by the `edges`, i.e., apply the H gate once on
+    /// each qubit that is part of an edge and then the CZ gate on each edge, on the
+    /// circuit and the tracker.
+    ///
+    /// Isolated qubits are not part of any edge, so they have to be prepared, i.e.,
+    /// H'ed, separately.
+    pub fn prepare_graph_state(&mut self, edges: &[(usize, usize)]) {
+        let mut qubits = edges.iter().flat_map(|&(a, b)| [a, b]).collect::<Vec<_>>();
+        qubits.sort_unstable();
+        qubits.dedup();
+        for bit in qubits {
+            self.h(bit);
+        }
+        for &(bit_a, bit_b) in edges {
+            self.cz(bit_a, bit_b);
+        }
+    }
 }
 
 impl<C, A, S, B> TrackedCircuit<C, Frames<A>, S>
@@ -294,6 +327,217 @@ where
     }
 }
 
+impl<C, A, S, B> TrackedCircuit<C, Frames<A>, S>
+where
+    S: Iterable<TI = PauliStack<B>>,
+    B: BooleanVector,
+{
+    /// Get the time ordering induced by the Pauli stacks in the additional storage,
+    /// i.e., the stacks of the measured qubits, via [induced_order::get_order]. `map`
+    /// maps the frames to the qubits that induced them; compare
+    /// [get_order](induced_order::get_order), including its panics.
+    pub fn induced_order(&self, map: &[usize]) -> PartialOrderGraph {
+        induced_order::get_order(self.storage.iter_pairs(), map)
+    }
+}
+
+impl<C, A, B> TrackedCircuit<C, Frames<A>, IncrementalOrder>
+where
+    C: CliffordCircuit,
+    A: IterableBase<T = PauliStack<B>>,
+    B: BooleanVector,
+{
+    /// Perform a **Measurement**, remove the according qubit from the tracker and
+    /// record its dependencies in the [IncrementalOrder], instead of storing its Pauli
+    /// stack. Returns the measurement outcome and, if the qubit is not in the tracker, a
+    /// [MissingBit] error.
+    ///
+    /// This builds the time ordering online, cf.
+    /// [finish_dependency_graph](Self::finish_dependency_graph), so that the stacks of
+    /// the measured qubits do not have to be kept around until the end.
+    ///
+    /// # Panics
+    /// Panics if [IncrementalOrder::insert] panics.
+    pub fn measure_and_record(
+        &mut self,
+        bit: usize,
+    ) -> (C::Outcome, Result<(), MissingBit>) {
+        let outcome = self.circuit.measure(bit);
+        match self.tracker.measure(bit) {
+            Ok(stack) => {
+                self.storage.insert(bit, &stack);
+                (outcome, Ok(()))
+            },
+            Err(e) => (outcome, Err(e)),
+        }
+    }
+
+    /// Get the time ordering of the qubits that have been measured with
+    /// [measure_and_record](Self::measure_and_record), cf. [IncrementalOrder::finish].
+    pub fn finish_dependency_graph(self) -> PartialOrderGraph {
+        self.storage.finish()
+    }
+}
+
+impl<C, A, S, P> TrackedCircuit<C, Live<A>, S>
+where
+    A: Base<TB = P>,
+    P: Pauli,
+{
+    /// Get the basis in which the qu`bit` has to be measured, so that the measurement
+    /// corresponds to measuring in the `nominal` basis without the tracked Pauli
+    /// corrections, cf. [MeasurementBasis::adjusted].
+    ///
+    /// # Panics
+    /// Panics if qu`bit` is not in the tracker.
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() { #![cfg_attr(coverage_nightly, coverage(off))]
+    /// # use std::f64::consts::FRAC_PI_4;
+    /// # use pauli_tracker::{circuit::{DummyCircuit, MeasurementBasis, TrackedCircuit},
+    /// #     collection::{BufferedVector, Init}, pauli::PauliDense, tracker::live::Live};
+    /// let mut circ = TrackedCircuit {
+    ///     circuit: DummyCircuit {},
+    ///     tracker: Live::<BufferedVector<PauliDense>>::init(1),
+    ///     storage: (),
+    /// };
+    /// circ.track_x(0);
+    /// assert_eq!(
+    ///     circ.adjusted_basis(0, MeasurementBasis::XY(FRAC_PI_4)),
+    ///     MeasurementBasis::XY(7.0 * FRAC_PI_4)
+    /// );
+    /// # }
+    /// ```
+    pub fn adjusted_basis(
+        &self,
+        bit: usize,
+        nominal: MeasurementBasis,
+    ) -> MeasurementBasis {
+        let pauli =
+            self.tracker.get(bit).unwrap_or_else(|| panic!("{}", MissingBit(bit)));
+        nominal.adjusted(pauli)
+    }
+}
+
+/// The basis of a single-qubit measurement in one of the planes of the Bloch sphere, as
+/// used in [MBQC], described by the angle in radians.
+///
+/// The angles are with respect to the first axis of the plane, i.e., the measured
+/// observables are
+/// - [XY](Self::XY)(α): cos(α) X + sin(α) Y,
+/// - [XZ](Self::XZ)(α): cos(α) Z + sin(α) X,
+/// - [YZ](Self::YZ)(α): cos(α) Z + sin(α) Y.
+///
+/// [MBQC]: https://doi.org/10.48550/arXiv.0910.1116
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub enum MeasurementBasis {
+    /// Measurement in the XY plane.
+    XY(f64),
+    /// Measurement in the XZ plane.
+    XZ(f64),
+    /// Measurement in the YZ plane.
+    YZ(f64),
+}
+
+impl MeasurementBasis {
+    /// Adjust the basis to the Pauli correction `pauli` that is (virtually) applied
+    /// before the measurement, i.e., conjugate the measured observable with `pauli`.
+    /// Measuring in the adjusted basis yields the same result as measuring in `self`
+    /// without the correction. The returned angle is normalized to [0, 2π).
+    ///
+    /// For example, in the XY plane an X correction flips the sign of the angle and a Z
+    /// correction adds π.
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() { #![cfg_attr(coverage_nightly, coverage(off))]
+    /// # use std::f64::consts::{FRAC_PI_2, PI};
+    /// # use pauli_tracker::{circuit::MeasurementBasis, pauli::{Pauli, PauliTuple}};
+    /// let basis = MeasurementBasis::XY(FRAC_PI_2);
+    /// assert_eq!(basis.adjusted(&PauliTuple::Z), MeasurementBasis::XY(3.0 * FRAC_PI_2));
+    /// assert_eq!(basis.adjusted(&PauliTuple::I), basis);
+    /// assert_eq!(
+    ///     MeasurementBasis::XZ(0.).adjusted(&PauliTuple::X),
+    ///     MeasurementBasis::XZ(PI)
+    /// );
+    /// # }
+    /// ```
+    pub fn adjusted(self, pauli: &impl Pauli) -> Self {
+        let (z, x) = (pauli.get_z(), pauli.get_x());
+        // the observable is cos(α) A + sin(α) B; conjugating with a Pauli flips the
+        // signs of A and/or B: flipping A maps α to π - α, flipping B maps α to -α, and
+        // flipping both maps α to α + π
+        let conjugate = |angle: f64, flip_a: bool, flip_b: bool| {
+            match (flip_a, flip_b) {
+                (false, false) => angle,
+                (true, false) => PI - angle,
+                (false, true) => -angle,
+                (true, true) => angle + PI,
+            }
+            .rem_euclid(TAU)
+        };
+        // a Pauli flips the sign of X if it has a Z component, the sign of Z if it has an
+        // X component, and the sign of Y if it is X or Z
+        match self {
+            Self::XY(angle) => Self::XY(conjugate(angle, z, x ^ z)),
+            Self::XZ(angle) => Self::XZ(conjugate(angle, x, z)),
+            Self::YZ(angle) => Self::YZ(conjugate(angle, x, x ^ z)),
+        }
+    }
+}
+
+/// Record the corrections that are induced by passing the tracked Paulis on qu`bit`
+/// through a (non-Clifford) T gate.
+///
+/// The T gate commutes with Z, but T X T^dagger is X times S^dagger, up to a phase, i.e.,
+/// for every frame where the Pauli on qu`bit` has a X component (X or Y), an additional
+/// S correction, conditioned on that frame, is required on qu`bit`. For each such
+/// frame, this function pushes `(bit, frame)` onto `dependencies`. The `tracker` is not
+/// changed (and it is not a Clifford update, so it is not a [Tracker] method); this is
+/// only metadata for the caller.
+///
+/// # Errors
+/// Errors with [MissingBit] if qu`bit` is not in the `tracker`.
+///
+/// # Examples
+/// ```
+/// # fn main() { #![cfg_attr(coverage_nightly, coverage(off))]
+/// use pauli_tracker::{
+///     circuit,
+///     collection::{BufferedVector, Init},
+///     pauli::PauliStack,
+///     tracker::{Tracker, frames::Frames},
+/// };
+/// let mut tracker = Frames::<BufferedVector<PauliStack<Vec<bool>>>>::init(2);
+/// tracker.track_z(0); // frame 0
+/// tracker.track_y(0); // frame 1
+/// tracker.track_x(1); // frame 2
+/// let mut dependencies = Vec::new();
+/// circuit::t_gate_correction(&tracker, 0, &mut dependencies).unwrap();
+/// assert_eq!(dependencies, vec![(0, 1)]);
+/// # }
+/// ```
+pub fn t_gate_correction<S, B>(
+    tracker: &Frames<S>,
+    bit: usize,
+    dependencies: &mut Vec<(usize, usize)>,
+) -> Result<(), MissingBit>
+where
+    S: Base<TB = PauliStack<B>>,
+    B: BooleanVector,
+{
+    let stack = tracker.get(bit).ok_or(MissingBit(bit))?;
+    dependencies.extend(
+        stack
+            .x
+            .iter_vals()
+            .enumerate()
+            .filter_map(|(frame, x)| if x { Some((bit, frame)) } else { None }),
+    );
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     // use bitvec::vec::BitVec;
@@ -345,9 +589,8 @@ mod tests {
         circ.tracker.new_qubit(2);
         circ.tracker.new_qubit(3);
         circ.tracker.new_qubit(4);
-        // note that the iterator of MappedVector is deterministic (that's not clear
-        // from the API, but from the source code); without that, the following wouldn't
-        // work
+        // note that the iteration order of MappedVector is deterministic; without that,
+        // the following wouldn't work
         let (outcome, r) = circ.measure_and_store_all();
         assert_eq!(outcome.len(), 2);
         assert_eq!(r.unwrap_err(), {
@@ -361,6 +604,88 @@ mod tests {
         r.unwrap()
     }
 
+    #[test]
+    fn prepare_graph_state() {
+        // the path 0 - 1 - 2 plus the edge 1 - 3 (so qubit 1 appears multiple times)
+        let edges = [(0, 1), (1, 2), (3, 1)];
+
+        let mut live = TrackedCircuit {
+            circuit: DummyCircuit {},
+            tracker: live::Live::<NaiveVector<PauliEnum>>::init(4),
+            storage: (),
+        };
+        live.track_x(0);
+        live.track_z(2);
+        live.prepare_graph_state(&edges);
+        // H: X0 -> Z0, Z2 -> X2; CZ(1, 2): X2 -> X2 Z1
+        assert_eq!(live.tracker.as_storage().0, [
+            PauliEnum::Z,
+            PauliEnum::Z,
+            PauliEnum::X,
+            PauliEnum::I
+        ]);
+
+        let mut circ = TrackedCircuit {
+            circuit: DummyCircuit {},
+            tracker: Frames::<BufferedVector<PauliBitVec>>::init(4),
+            storage: (),
+        };
+        let mut check = circ.clone();
+        circ.track_x(1);
+        circ.prepare_graph_state(&edges);
+        check.track_x(1);
+        for bit in 0..4 {
+            check.h(bit);
+        }
+        for (a, b) in edges {
+            check.cz(a, b);
+        }
+        assert_eq!(circ.tracker, check.tracker);
+    }
+
+    #[test]
+    fn adjusted_basis() {
+        use std::f64::consts::FRAC_PI_3;
+
+        #[cfg_attr(coverage_nightly, coverage(off))]
+        fn angle(basis: MeasurementBasis) -> f64 {
+            match basis {
+                MeasurementBasis::XY(angle) => angle,
+                _ => panic!("wrong plane"),
+            }
+        }
+
+        let mut circ = TrackedCircuit {
+            circuit: DummyCircuit {},
+            tracker: Live::<PauliDense>::init(4),
+            storage: (),
+        };
+        circ.track_x(1);
+        circ.track_z(2);
+        circ.track_y(3);
+        let nominal = MeasurementBasis::XY(FRAC_PI_3);
+        // (-1)^x α + z π
+        for (bit, expected) in [
+            (0, FRAC_PI_3),
+            (1, 5. * FRAC_PI_3),
+            (2, 4. * FRAC_PI_3),
+            (3, 2. * FRAC_PI_3),
+        ] {
+            assert!((angle(circ.adjusted_basis(bit, nominal)) - expected).abs() < 1e-12);
+        }
+
+        // Y flips both axes of the XZ plane, but only Z of the YZ plane
+        let y = PauliDense::new_y();
+        let adjusted = MeasurementBasis::XZ(FRAC_PI_3).adjusted(&y);
+        assert!(
+            matches!(adjusted, MeasurementBasis::XZ(a) if (a - 4. * FRAC_PI_3).abs() < 1e-12)
+        );
+        let adjusted = MeasurementBasis::YZ(FRAC_PI_3).adjusted(&y);
+        assert!(
+            matches!(adjusted, MeasurementBasis::YZ(a) if (a - 2. * FRAC_PI_3).abs() < 1e-12)
+        );
+    }
+
     #[test]
     fn move_and_remove() {
         let mut circ = TrackedCircuit {
@@ -578,23 +903,205 @@ mod tests {
         // (3, PauliVec::try_from("0000000", "1001110").unwrap()),
         // (6, PauliVec::try_from("0000000", "0101101").unwrap()),
         // (9, PauliVec::try_from("0010111", "0000000").unwrap()),
-        check
-            .get_mut(3)
-            .unwrap()
-            .set_storage((results[0] + results[3] + results[4] + results[5]) % 2);
-        check
-            .get_mut(6)
-            .unwrap()
-            .set_storage((results[1] + results[3] + results[4] + results[6]) % 2);
-        check
-            .get_mut(9)
-            .unwrap()
+        check[3].set_storage((results[0] + results[3] + results[4] + results[5]) % 2);
+        check[6].set_storage((results[1] + results[3] + results[4] + results[6]) % 2);
+        check[9]
             .set_storage(((results[2] + results[4] + results[5] + results[6]) % 2) * 2);
 
         // println!("{:?}", circ.tracker);
         assert_eq!(circ.tracker, check);
     }
 
+    #[test]
+    fn replay() {
+        #[cfg_attr(coverage_nightly, coverage(off))]
+        fn run<C: CliffordCircuit<Outcome = bool>>(
+            circuit: C,
+        ) -> (Live<PauliDense>, Vec<bool>) {
+            let mut circ = TrackedCircuit {
+                circuit,
+                tracker: Live::init(4),
+                storage: (),
+            };
+            let mut outcomes = Vec::new();
+            for (origin, new) in [(0, 2), (1, 3), (2, 0), (3, 1), (0, 2)] {
+                circ.cx(origin, new);
+                circ.move_z_to_z(origin, new);
+                let outcome = circ.circuit.measure(origin);
+                if outcome {
+                    circ.track_z(new);
+                }
+                outcomes.push(outcome);
+                circ.h(new);
+            }
+            (circ.tracker, outcomes)
+        }
+
+        for _ in 0..10 {
+            let (tracker, outcomes) = run(RandomMeasurementCircuit {});
+            let (replayed, replayed_outcomes) = run(ReplayCircuit::new(outcomes.clone()));
+            assert_eq!(replayed_outcomes, outcomes);
+            assert_eq!(replayed, tracker);
+        }
+
+        let mut circuit = ReplayCircuit::new(vec![true]);
+        assert!(circuit.measure(0));
+        assert!(circuit.remaining().is_empty());
+    }
+
+    #[test]
+    fn minimal_circuit() {
+        #[derive(Debug, Default)]
+        struct Recorder(Vec<(&'static str, Vec<usize>)>);
+        impl CliffordCircuit for Recorder {
+            type Outcome = usize;
+            #[cfg_attr(coverage_nightly, coverage(off))]
+            fn s(&mut self, bit: usize) {
+                self.0.push(("s", vec![bit]));
+            }
+            #[cfg_attr(coverage_nightly, coverage(off))]
+            fn h(&mut self, bit: usize) {
+                self.0.push(("h", vec![bit]));
+            }
+            #[cfg_attr(coverage_nightly, coverage(off))]
+            fn cz(&mut self, bit_a: usize, bit_b: usize) {
+                self.0.push(("cz", vec![bit_a, bit_b]));
+            }
+            #[cfg_attr(coverage_nightly, coverage(off))]
+            fn measure(&mut self, bit: usize) -> usize {
+                bit
+            }
+        }
+
+        let mut circ = TrackedCircuit {
+            circuit: Recorder::default(),
+            tracker: Live::<PauliDense>::init(2),
+            storage: (),
+        };
+        let mut check = Live::<PauliDense>::init(2);
+
+        circ.track_x(0);
+        check.track_x(0);
+        circ.sdg(1);
+        circ.cx(0, 1);
+        circ.sh(0);
+        check.sdg(1);
+        check.cx(0, 1);
+        check.sh(0);
+
+        assert_eq!(circ.circuit.0, vec![
+            ("s", vec![1]),
+            ("h", vec![1]),
+            ("cz", vec![0, 1]),
+            ("h", vec![1]),
+            ("h", vec![0]),
+            ("s", vec![0]),
+        ]);
+        assert_eq!(circ.tracker, check);
+        assert_eq!(circ.measure(1), 1);
+    }
+
+    #[test]
+    fn t_injection() {
+        // T-gate injection on qubit 0 with a magic state on qubit 1: cx(0, 1), measure 1
+        // and correct 0; the correction, and everything tracked before, passes through
+        // the following T gate on qubit 0
+        let mut circ = TrackedCircuit {
+            circuit: DummyCircuit {},
+            tracker: Frames::<BufferedVector<PauliStack<Vec<bool>>>>::init(3),
+            storage: (),
+        };
+        circ.track_x(0); // 0: an earlier X correction on the data qubit
+        circ.track_z(0); // 1: an earlier Z correction on the data qubit
+        circ.cx(0, 1);
+        circ.track_z(0); // 2: the S correction of the injection, up to Paulis
+        circ.track_x(2); // 3: unrelated
+        let mut dependencies = Vec::new();
+        t_gate_correction(&circ.tracker, 0, &mut dependencies).unwrap();
+        t_gate_correction(&circ.tracker, 1, &mut dependencies).unwrap();
+        assert_eq!(dependencies, vec![(0, 0), (1, 0)]);
+        assert_eq!(
+            t_gate_correction(&circ.tracker, 3, &mut dependencies),
+            Err(MissingBit(3))
+        );
+    }
+
+    #[test]
+    fn incremental_order() {
+        #[cfg_attr(coverage_nightly, coverage(off))]
+        fn normalized(mut graph: PartialOrderGraph) -> PartialOrderGraph {
+            induced_order::sort_layers_by_bits(&mut graph);
+            for layer in graph.iter_mut() {
+                for (_, deps) in layer.iter_mut() {
+                    deps.sort_unstable();
+                }
+            }
+            graph
+        }
+
+        const NUM: usize = 8;
+        let mut batch = TrackedCircuit {
+            circuit: DummyCircuit {},
+            tracker: Frames::<Map<PauliStack<Vec<bool>>>>::init(NUM),
+            storage: Map::<PauliStack<Vec<bool>>>::default(),
+        };
+        let mut online = TrackedCircuit {
+            circuit: DummyCircuit {},
+            tracker: Frames::<Map<PauliStack<Vec<bool>>>>::init(NUM),
+            storage: IncrementalOrder::default(),
+        };
+        let mut map = Vec::new();
+        // a linear cluster-like pattern: measuring qubit i induces corrections on the
+        // next qubits
+        for i in 0..NUM - 2 {
+            for bit in [i + 1, i + 2] {
+                batch.cx(i, bit);
+                online.cx(i, bit);
+            }
+            batch.measure_and_store(i).1.unwrap();
+            online.measure_and_record(i).1.unwrap();
+            batch.track_z(i + 1);
+            online.track_z(i + 1);
+            online.storage.push_frame_origin(i);
+            batch.track_x(i + 2);
+            online.track_x(i + 2);
+            online.storage.push_frame_origin(i);
+            map.extend([i, i]);
+        }
+        for bit in [NUM - 2, NUM - 1] {
+            batch.measure_and_store(bit).1.unwrap();
+            online.measure_and_record(bit).1.unwrap();
+        }
+        assert_eq!(online.measure_and_record(0).1, Err(MissingBit(0)));
+
+        let expected = normalized(batch.induced_order(&map));
+        assert!(expected.len() > 2);
+        assert_eq!(normalized(online.finish_dependency_graph()), expected);
+    }
+
+    #[test]
+    fn induced_order() {
+        let mut circ = TrackedCircuit {
+            circuit: DummyCircuit {},
+            tracker: Frames::<Map<PauliStack<BitVec>>>::init(4),
+            storage: Map::<_>::default(),
+        };
+
+        circ.track_x(1); // induced by the measurement on 0
+        circ.cx(1, 2);
+        circ.measure_and_store(0).1.unwrap();
+        circ.track_z(3); // induced by the measurement on 1
+        circ.measure_and_store_all().1.unwrap();
+
+        let mut graph = circ.induced_order(&[0, 1]);
+        induced_order::sort_layers_by_bits(&mut graph);
+        assert_eq!(graph, vec![
+            vec![(0, vec![])],
+            vec![(1, vec![0]), (2, vec![0])],
+            vec![(3, vec![1])]
+        ]);
+    }
+
     #[test]
     fn another_graph_test() {
         let mut circ = TrackedCircuit {
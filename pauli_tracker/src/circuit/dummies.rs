@@ -38,3 +38,40 @@ impl CliffordCircuit for RandomMeasurementCircuit {
         rand::random::<bool>()
     }
 }
+
+/// A circuit where the gates do nothing, but the measurements return pre-recorded
+/// outcomes in order, e.g., to replay a tracking session with the outcomes that were
+/// observed on hardware or recorded with a [RandomMeasurementCircuit].
+///
+/// # Panics
+/// [measure](CliffordCircuit::measure) panics if all outcomes have already been
+/// replayed.
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ReplayCircuit {
+    outcomes: Vec<bool>,
+    position: usize,
+}
+
+impl ReplayCircuit {
+    /// Create a new circuit that replays the `outcomes`.
+    pub fn new(outcomes: Vec<bool>) -> Self {
+        Self { outcomes, position: 0 }
+    }
+
+    /// Get the outcomes that have not been replayed yet.
+    pub fn remaining(&self) -> &[bool] {
+        &self.outcomes[self.position..]
+    }
+}
+
+impl CliffordCircuit for ReplayCircuit {
+    type Outcome = bool;
+    impl_dummy_gates!();
+    fn measure(&mut self, bit: usize) -> bool {
+        let outcome = *self.outcomes.get(self.position).unwrap_or_else(|| {
+            panic!("no recorded outcomes left to replay the measurement of qubit {bit}")
+        });
+        self.position += 1;
+        outcome
+    }
+}
@@ -44,6 +44,20 @@ macro_rules! trait_gates {
         }
         coset!(shs, "SHS", (sx, "sqrt(X)"), (sxdg, "sqrt(X)^dagger"), (hyz, "H_yz"),);
 
+        #[doc = single_doc_standard!("V")]
+        /// This is the name of the [sqrt(X)](Self::sx) gate in many hardware
+        /// toolchains.
+        fn v(&mut self, bit: usize) {
+            self.sx(bit);
+        }
+
+        #[doc = single_doc_standard!("V^dagger")]
+        /// This is the name of the [sqrt(X)^dagger](Self::sxdg) gate in many hardware
+        /// toolchains.
+        fn vdg(&mut self, bit: usize) {
+            self.sxdg(bit);
+        }
+
         #[doc = double_doc!("Control X (Control Not)", control, target)]
         fn cx(&mut self, control: usize, target: usize) {
             self.h(target);
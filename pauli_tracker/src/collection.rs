@@ -6,6 +6,8 @@ however, the traits are not meant to be a general good API for collections, but
 for our use cases.
 */
 
+use std::iter;
+
 /// A very basic interface for a collection of elements.
 ///
 /// It is the the minimal interface that we need for the
@@ -53,6 +55,12 @@ pub trait Base {
 }
 
 /// Collections that can be iterated over.
+///
+/// Together with [Base] (cf. [IterableBase]), this is the interface that a custom
+/// storage has to implement to be used as backend for the
+/// [Frames](crate::tracker::frames::Frames) tracker. The tracker relies on
+/// [iter_pairs_mut](Self::iter_pairs_mut) for all operations that act on the stacks of
+/// all qubits at once, e.g., tracking a new frame.
 // instead of requiring that &T and &mut T implement IntoIterator, we have the iter and
 // iter_mut methods, respectively; the reason is that having the additional bounds would
 // either need an annoying lifetime or HRTBs, the latter would limit the use cases of
@@ -80,12 +88,58 @@ pub trait Iterable {
 
     /// Get an [Iterator] over the tuples of keys and references of the corresponding
     /// elements.
+    ///
+    /// Every key that is accessible via [Base::get] (if implemented) has to be yielded
+    /// exactly once; the order is arbitrary.
     fn iter_pairs(&self) -> Self::Iter<'_>;
 
     /// Get an [Iterator] over the tuples of keys and mutable references of the
     /// corresponding elements.
+    ///
+    /// Every key that is accessible via [Base::get_mut] (if implemented) has to be
+    /// yielded exactly once; the order is arbitrary, but it should be the same as for
+    /// [iter_pairs](Self::iter_pairs).
     fn iter_pairs_mut(&mut self) -> Self::IterMut<'_>;
 
+    /// Call `f` on all elements, e.g., to push a new frame onto all stacks.
+    ///
+    /// The default implementation uses [iter_pairs_mut](Self::iter_pairs_mut); the
+    /// implementations for the contiguous [BufferedVector] and [NaiveVector] overwrite it
+    /// with a tight loop over the elements, skipping the keys.
+    fn for_each_value_mut<F: FnMut(&mut Self::TI)>(&mut self, mut f: F) {
+        for (_, value) in self.iter_pairs_mut() {
+            f(value);
+        }
+    }
+
+    /// Get an [Iterator] over the references of the elements, without the keys, in the
+    /// same order as [iter_pairs](Self::iter_pairs).
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() { #![cfg_attr(coverage_nightly, coverage(off))]
+    /// # use pauli_tracker::collection::{Base, Iterable, MappedVector};
+    /// let mut vector = MappedVector::<char>::new();
+    /// vector.insert(3, 'a');
+    /// vector.insert(0, 'b');
+    /// assert_eq!(vector.values().collect::<Vec<_>>(), vec![&'a', &'b']);
+    /// # }
+    /// ```
+    #[allow(clippy::type_complexity)] // the fn pointer, since we cannot name the closure
+    fn values(&self) -> iter::Map<Self::Iter<'_>, fn((usize, &Self::TI)) -> &Self::TI> {
+        self.iter_pairs().map(|(_, value)| value)
+    }
+
+    /// Get an [Iterator] over the mutable references of the elements, without the keys,
+    /// in the same order as [iter_pairs_mut](Self::iter_pairs_mut), e.g., for operations
+    /// that don't depend on the qubits.
+    #[allow(clippy::type_complexity)]
+    fn values_mut(
+        &mut self,
+    ) -> iter::Map<Self::IterMut<'_>, fn((usize, &mut Self::TI)) -> &mut Self::TI> {
+        self.iter_pairs_mut().map(|(_, value)| value)
+    }
+
     /// Sort the collection according to the keys.
     fn sort_by_key(&self) -> Vec<(usize, &Self::TI)> {
         let mut ret = self.iter_pairs().collect::<Vec<(usize, &Self::TI)>>();
@@ -149,3 +203,169 @@ pub use buffered_vector::BufferedVector;
 pub use map::Map;
 pub use mapped_vector::MappedVector;
 pub use naive_vector::NaiveVector;
+
+#[cfg(test)]
+mod tests {
+    use std::{iter, slice};
+
+    use coverage_helper::test;
+
+    use super::*;
+    use crate::{
+        pauli::{PauliStack, PauliTuple},
+        tracker::{Tracker, frames::Frames},
+    };
+
+    // a minimal custom storage, to check that the documented interface is enough for the
+    // Frames tracker
+    #[derive(Debug, Clone, Default, PartialEq)]
+    struct PairList<T>(Vec<(usize, T)>);
+
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn as_ref<T>((key, value): &(usize, T)) -> (usize, &T) {
+        (*key, value)
+    }
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn as_mut<T>((key, value): &mut (usize, T)) -> (usize, &mut T) {
+        (*key, value)
+    }
+
+    impl<T> Base for PairList<T> {
+        type TB = T;
+        #[cfg_attr(coverage_nightly, coverage(off))]
+        fn insert(&mut self, key: usize, value: T) -> Option<T> {
+            match self.get_mut(key) {
+                Some(old) => Some(std::mem::replace(old, value)),
+                None => {
+                    self.0.push((key, value));
+                    None
+                },
+            }
+        }
+        #[cfg_attr(coverage_nightly, coverage(off))]
+        fn remove(&mut self, key: usize) -> Option<T> {
+            let position = self.0.iter().position(|(k, _)| *k == key)?;
+            Some(self.0.remove(position).1)
+        }
+        #[cfg_attr(coverage_nightly, coverage(off))]
+        fn get(&self, key: usize) -> Option<&T> {
+            self.0.iter().find(|(k, _)| *k == key).map(|(_, v)| v)
+        }
+        #[cfg_attr(coverage_nightly, coverage(off))]
+        fn get_mut(&mut self, key: usize) -> Option<&mut T> {
+            self.0.iter_mut().find(|(k, _)| *k == key).map(|(_, v)| v)
+        }
+        #[cfg_attr(coverage_nightly, coverage(off))]
+        fn get_two_mut(
+            &mut self,
+            key_a: usize,
+            key_b: usize,
+        ) -> Option<(&mut T, &mut T)> {
+            assert_ne!(key_a, key_b);
+            let mut a = None;
+            let mut b = None;
+            for (key, value) in self.0.iter_mut() {
+                if *key == key_a {
+                    a = Some(value);
+                } else if *key == key_b {
+                    b = Some(value);
+                }
+            }
+            Some((a?, b?))
+        }
+        #[cfg_attr(coverage_nightly, coverage(off))]
+        fn len(&self) -> usize {
+            self.0.len()
+        }
+    }
+
+    impl<T> Iterable for PairList<T> {
+        type TI = T;
+        type Iter<'l>
+            = iter::Map<slice::Iter<'l, (usize, T)>, fn(&(usize, T)) -> (usize, &T)>
+        where
+            T: 'l;
+        type IterMut<'l>
+            = iter::Map<
+            slice::IterMut<'l, (usize, T)>,
+            fn(&mut (usize, T)) -> (usize, &mut T),
+        >
+        where
+            T: 'l;
+        #[cfg_attr(coverage_nightly, coverage(off))]
+        fn iter_pairs(&self) -> Self::Iter<'_> {
+            self.0.iter().map(as_ref)
+        }
+        #[cfg_attr(coverage_nightly, coverage(off))]
+        fn iter_pairs_mut(&mut self) -> Self::IterMut<'_> {
+            self.0.iter_mut().map(as_mut)
+        }
+    }
+
+    impl<T> IterableBase for PairList<T> {
+        type T = T;
+    }
+
+    impl<T: Default> Init for PairList<T> {
+        #[cfg_attr(coverage_nightly, coverage(off))]
+        fn init(len: usize) -> Self {
+            Self((0..len).map(|key| (key, T::default())).collect())
+        }
+    }
+
+    #[test]
+    fn values() {
+        #[cfg_attr(coverage_nightly, coverage(off))]
+        fn check<S: IterableBase<T = usize> + Init>() {
+            let mut collection = S::init(5);
+            for (key, value) in collection.iter_pairs_mut() {
+                *value = 10 * key;
+            }
+            assert_eq!(
+                collection.values().collect::<Vec<_>>(),
+                collection.iter_pairs().map(|(_, value)| value).collect::<Vec<_>>()
+            );
+            for value in collection.values_mut() {
+                *value += 1;
+            }
+            let mut pairs = collection
+                .iter_pairs()
+                .map(|(key, value)| (key, *value))
+                .collect::<Vec<_>>();
+            pairs.sort_by_key(|(key, _)| *key);
+            assert_eq!(pairs, (0..5).map(|key| (key, 10 * key + 1)).collect::<Vec<_>>());
+        }
+        check::<PairList<usize>>();
+        check::<BufferedVector<usize>>();
+        check::<Map<usize>>();
+        check::<MappedVector<usize>>();
+        check::<NaiveVector<usize>>();
+    }
+
+    #[test]
+    fn custom_storage_as_frames_backend() {
+        const NUM: usize = 4;
+        let mut custom = Frames::<PairList<PauliStack<Vec<bool>>>>::init(NUM);
+        let mut check = Frames::<BufferedVector<PauliStack<Vec<bool>>>>::init(NUM);
+        for i in 0..9 {
+            custom.track_y(i % NUM);
+            check.track_y(i % NUM);
+            custom.cx(i % NUM, (i + 2) % NUM);
+            check.cx(i % NUM, (i + 2) % NUM);
+            custom.sh((i + 1) % NUM);
+            check.sh((i + 1) % NUM);
+        }
+        custom.apply_to_all(PauliStack::h);
+        check.apply_to_all(PauliStack::h);
+        custom.grow_frames_to(12);
+        check.grow_frames_to(12);
+        assert_eq!(custom.pop_frame::<PauliTuple>(), check.pop_frame::<PauliTuple>());
+
+        assert_eq!(custom.frames_num(), check.frames_num());
+        assert_eq!(
+            custom.iter_frames::<PauliTuple>().collect::<Vec<_>>(),
+            check.iter_frames::<PauliTuple>().collect::<Vec<_>>()
+        );
+        assert_eq!(custom.as_storage().sort_by_key(), check.as_storage().sort_by_key());
+    }
+}
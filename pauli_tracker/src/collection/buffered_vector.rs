@@ -15,6 +15,24 @@ use crate::slice_extension::GetTwoMutSlice;
 /// Since we cannot arbitrarily insert and remove elements, inserting as only allowed
 /// for keys bigger than the current length of the vector (inserting additional buffer
 /// elements if necessary), and only the last element can be removed.
+///
+/// More precisely, [insert](Base::insert)ing at a key bigger than the current length
+/// grows the vector automatically, filling the gap with [Default] elements; so keys
+/// may arrive out of order without pre-sizing the vector. Note that, when used as
+/// storage for [Frames](crate::tracker::frames::Frames), the default
+/// [PauliStack](crate::pauli::PauliStack)s in the gap are empty, i.e., they do not
+/// have the length of the other stacks; if those qubits are used later on, initialize
+/// them with [new_qubit](crate::tracker::Tracker::new_qubit).
+///
+/// # Examples
+/// ```
+/// # fn main() { #![cfg_attr(coverage_nightly, coverage(off))]
+/// # use pauli_tracker::collection::{Base, BufferedVector};
+/// let mut vec = BufferedVector::<u8>::new();
+/// assert_eq!(vec.insert(3, 4), None);
+/// assert_eq!(vec, BufferedVector(vec![0, 0, 0, 4]));
+/// # }
+/// ```
 #[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct BufferedVector<T>(pub Vec<T>);
@@ -161,6 +179,10 @@ where
     fn iter_pairs_mut(&mut self) -> Self::IterMut<'_> {
         self.into_iter()
     }
+
+    fn for_each_value_mut<F: FnMut(&mut T)>(&mut self, f: F) {
+        self.0.iter_mut().for_each(f);
+    }
 }
 
 impl<T> Init for BufferedVector<T>
@@ -179,3 +201,25 @@ where
     type T = T;
 }
 impl<T> Full for BufferedVector<T> where T: Default + Clone {}
+
+#[cfg(test)]
+mod tests {
+    use coverage_helper::test;
+
+    use super::*;
+    use crate::pauli::PauliStack;
+
+    #[test]
+    fn insert_out_of_range() {
+        let mut vec = BufferedVector::<PauliStack<Vec<bool>>>::new();
+        let stack = PauliStack::try_from_str("101", "011").unwrap();
+        assert_eq!(vec.insert(1000, stack.clone()), None);
+        assert_eq!(vec.len(), 1001);
+        assert_eq!(vec.get(1000), Some(&stack));
+        for bit in 0..1000 {
+            assert_eq!(vec.get(bit), Some(&PauliStack::default()));
+        }
+        assert_eq!(vec.insert(5, stack.clone()), Some(PauliStack::default()));
+        assert_eq!(vec.get(5), Some(&stack));
+    }
+}
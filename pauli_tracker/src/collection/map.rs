@@ -9,6 +9,11 @@ use super::{Base, Full, Init, Iterable, IterableBase};
 
 /// A [HashMap](https://docs.rs/hashbrown/latest/hashbrown/struct.HashMap.html#) with
 /// [usize] keys.
+///
+/// Note that the iteration order is arbitrary and, with the default hasher, it may
+/// differ between runs. Use [MappedVector](super::MappedVector) or
+/// [BufferedVector](super::BufferedVector) if the order is important, or sort the
+/// elements, e.g., with [Full::into_sorted_by_key].
 pub type Map<T, S = DefaultHashBuilder> = HashMap<usize, T, S>;
 
 impl<T, S> Base for Map<T, S>
@@ -20,6 +20,13 @@ use crate::slice_extension::GetTwoMutSlice;
 /// [HashMap] to get the right index in the storage. Inserting elements is done by
 /// pushing to the storage and removing is done via swap-removes.
 ///
+/// # Iteration order
+/// The iteration order is deterministic and part of the API: The elements are iterated
+/// in the order of the inner storage, i.e., in insertion order, except that inserting
+/// an already present key replaces the element in place and removing a key moves the
+/// last element into the position of the removed element (swap-remove).
+/// [compact](Self::compact) sorts the elements by their keys.
+///
 /// [HashMap]: https://docs.rs/hashbrown/latest/hashbrown/struct.HashMap.html#
 #[derive(Debug, Clone, Default)]
 /// instead of going through _MappedVector we should implement it directly, at least for
@@ -140,6 +147,51 @@ where
         &self.inverse_position
     }
 
+    /// Get the inner storage and the inverse position of the elements as slices, i.e.,
+    /// the return values of [storage](Self::storage) and
+    /// [inverse_position](Self::inverse_position). The element at index `i` in the
+    /// first slice belongs to the key at index `i` in the second slice.
+    ///
+    /// This is the same raw view that the C bindings expose on the storage, but safe.
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() { #![cfg_attr(coverage_nightly, coverage(off))]
+    /// # use pauli_tracker::collection::{Base, MappedVector};
+    /// let mut vector = MappedVector::<char>::new();
+    /// vector.insert(3, 'a');
+    /// vector.insert(0, 'b');
+    /// assert_eq!(vector.as_slices(), (&['a', 'b'][..], &[3, 0][..]));
+    /// # }
+    /// ```
+    pub fn as_slices(&self) -> (&[T], &[usize]) {
+        (&self.storage, &self.inverse_position)
+    }
+
+    /// Rebuild the inner storage, sorted by the keys, and shrink all inner buffers as
+    /// much as possible, reclaiming the memory from removed elements.
+    ///
+    /// Note that this changes the order of the elements in [storage](Self::storage) and
+    /// [inverse_position](Self::inverse_position), i.e., the iteration order.
+    pub fn compact(&mut self) {
+        let mut pairs = mem::take(&mut self.inverse_position)
+            .into_iter()
+            .zip(mem::take(&mut self.storage))
+            .collect::<Vec<_>>();
+        pairs.sort_unstable_by_key(|(key, _)| *key);
+        let (inverse_position, storage): (Vec<_>, Vec<_>) = pairs.into_iter().unzip();
+        self.position.clear();
+        self.position.extend(
+            inverse_position
+                .iter()
+                .enumerate()
+                .map(|(position, key)| (*key, position)),
+        );
+        self.position.shrink_to_fit();
+        self.storage = storage;
+        self.inverse_position = inverse_position;
+    }
+
     fn insert(&mut self, key: usize, value: T) -> Option<T> {
         if let Some(&key) = self.position.get(&key) {
             let old = mem::replace(self.storage.index_mut(key), value);
@@ -296,3 +348,80 @@ where
     type T = T;
 }
 impl<T: Clone + Default, S: BuildHasher + Default> Full for MappedVector<T, S> {}
+
+#[cfg(test)]
+mod tests {
+    use coverage_helper::test;
+
+    use super::*;
+
+    #[test]
+    fn iteration_order() {
+        let mut vector = MappedVector::<char>::new();
+        for (key, value) in [(4, 'a'), (1, 'b'), (7, 'c'), (0, 'd'), (3, 'e')] {
+            vector.insert(key, value);
+        }
+        vector.remove(1); // 3 is moved into 1's position
+        vector.insert(7, 'f'); // replaced in place
+        vector.insert(2, 'g'); // appended
+        vector.remove(2); // the last one, nothing is moved
+        vector.remove(4); // 0 is moved into 4's position
+        assert_eq!(vector.into_iter().collect::<Vec<_>>(), vec![
+            (0, 'd'),
+            (3, 'e'),
+            (7, 'f')
+        ]);
+    }
+
+    #[test]
+    fn as_slices() {
+        let mut vector = MappedVector::<char>::new();
+        assert_eq!(vector.as_slices(), (&[][..], &[][..]));
+        for (key, value) in [(4, 'a'), (1, 'b'), (7, 'c')] {
+            vector.insert(key, value);
+        }
+        assert_eq!(vector.as_slices(), (&['a', 'b', 'c'][..], &[4, 1, 7][..]));
+        vector.remove(4);
+        let (storage, inverse_position) = vector.as_slices();
+        assert_eq!(storage, vector.storage());
+        assert_eq!(inverse_position, vector.inverse_position());
+        assert_eq!(
+            inverse_position
+                .iter()
+                .copied()
+                .zip(storage.iter().copied())
+                .collect::<Vec<_>>(),
+            vector
+                .iter_pairs()
+                .map(|(key, value)| (key, *value))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn compact() {
+        let mut vector = MappedVector::<usize>::new();
+        for key in 0..1000 {
+            vector.insert(key, key + 1);
+        }
+        for key in (0..1000).filter(|key| key % 100 != 7) {
+            vector.remove(key);
+        }
+        let capacity = vector.storage.capacity();
+
+        vector.compact();
+
+        assert!(vector.storage.capacity() < capacity);
+        // the exact capacity after shrinking is up to the allocator
+        assert!(vector.storage.capacity() >= vector.len());
+        assert_eq!(vector.len(), 10);
+        assert_eq!(vector.storage, (0..10).map(|i| 100 * i + 8).collect::<Vec<_>>());
+        assert_eq!(
+            vector.inverse_position,
+            (0..10).map(|i| 100 * i + 7).collect::<Vec<_>>()
+        );
+        for key in 0..1000 {
+            assert_eq!(vector.get(key).copied(), (key % 100 == 7).then_some(key + 1));
+        }
+    }
+}
@@ -129,6 +129,10 @@ where
     fn iter_pairs_mut(&mut self) -> Self::IterMut<'_> {
         self.into_iter()
     }
+
+    fn for_each_value_mut<F: FnMut(&mut T)>(&mut self, f: F) {
+        self.0.iter_mut().for_each(f);
+    }
 }
 
 impl<T> Init for NaiveVector<T>
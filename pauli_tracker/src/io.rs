@@ -0,0 +1,467 @@
+/*!
+A stable binary file format for [Frames] trackers.
+
+In contrast to the serde representation, which follows the internal structure of the
+types, this format is fixed and documented, so that it can be used to exchange trackers
+between different versions of this crate and with the other language bindings. It
+neither depends on the storage type nor on the [BooleanVector] type of the tracker.
+
+# Format
+All integers are little-endian.
+
+| bytes | content |
+| --- | --- |
+| 4 | the magic bytes `PTFR` |
+| 4 | the format version as [u32], currently [VERSION] |
+| 8 | the number of qubits as [u64] |
+| 8 | the number of frames, i.e., [Frames::frames_num], as [u64] |
+
+followed by an entry for each qubit, strictly ordered by the qubits' indices:
+
+| bytes | content |
+| --- | --- |
+| 8 | the qubit's index as [u64] |
+| ... | the qubit's stack, encoded as [PauliStack::canonical_bytes] |
+
+where the `z` and `x` part of each stack have exactly the length of the number of frames.
+
+Readers reject files with a larger version than they know, while the versions they know
+stay readable, i.e., a new version is only introduced together with a reader for it.
+
+The reader treats its input as untrusted: it never allocates more memory than what is
+justified by the data that is actually present. In particular, storages that don't
+support sparse qubit indices, like [BufferedVector] or [NaiveVector], only accept the
+qubits `0, 1, 2, ...`, which is what [write_frames] produces for them.
+
+[BufferedVector]: crate::collection::BufferedVector
+[NaiveVector]: crate::collection::NaiveVector
+*/
+
+use std::io::{self, Read, Write};
+
+use thiserror::Error;
+
+use crate::{
+    boolean_vector::BooleanVector,
+    collection::{Base, IterableBase},
+    pauli::PauliStack,
+    tracker::frames::Frames,
+};
+
+/// The magic bytes at the beginning of each file.
+pub const MAGIC: [u8; 4] = *b"PTFR";
+
+/// The version of the format that is written by [write_frames].
+pub const VERSION: u32 = 1;
+
+/// The Error when reading [Frames] with [read_frames].
+#[derive(Debug, Error)]
+pub enum ReadError {
+    /// The underlying reader failed, e.g., because the data ended unexpectedly.
+    #[error(transparent)]
+    Io(#[from] io::Error),
+    /// The data doesn't start with [MAGIC].
+    #[error("invalid magic bytes {0:?}")]
+    Magic([u8; 4]),
+    /// The version is not supported, i.e., it is 0 or larger than [VERSION].
+    #[error("unsupported version {0}, expected 1 to {VERSION}")]
+    Version(u32),
+    /// The qubit is not larger than the previous one, i.e., the qubits are not strictly
+    /// ordered, which includes duplicates.
+    #[error("qubit {qubit} follows qubit {previous}, but the qubits have to be ordered")]
+    UnorderedQubit {
+        /// The qubit.
+        qubit: usize,
+        /// The previous qubit.
+        previous: usize,
+    },
+    /// The qubit is not the next one, but the storage doesn't support sparse qubits,
+    /// cf. the [module documentation](self).
+    #[error("expected qubit {expected}, found {qubit}; the storage doesn't support gaps")]
+    SparseQubit {
+        /// The qubit.
+        qubit: usize,
+        /// The expected qubit.
+        expected: usize,
+    },
+    /// The (z or x part of the) stack of a qubit doesn't have the length of the number
+    /// of frames.
+    #[error(
+        "the stack of qubit {qubit} has length {len}, but there are {frames_num} frames"
+    )]
+    StackLength {
+        /// The qubit.
+        qubit: usize,
+        /// The length of the (z or x part of the) stack.
+        len: usize,
+        /// The number of frames.
+        frames_num: usize,
+    },
+}
+
+/// Write the `frames` to the `writer` in the stable [format](self#format).
+///
+/// # Examples
+/// ```
+/// # fn main() { #![cfg_attr(coverage_nightly, coverage(off))]
+/// # use pauli_tracker::{collection::{BufferedVector, Init, Map}, io, pauli::PauliStack,
+/// #     tracker::{Tracker, frames::Frames}};
+/// let mut frames = Frames::<Map<PauliStack<Vec<bool>>>>::init(2);
+/// frames.track_x(0);
+/// frames.cx(0, 1);
+/// let mut bytes = Vec::new();
+/// io::write_frames(&mut bytes, &frames).unwrap();
+/// let read: Frames<BufferedVector<PauliStack<Vec<bool>>>> =
+///     io::read_frames(bytes.as_slice()).unwrap();
+/// assert!(frames.logically_eq(&read));
+/// # }
+/// ```
+pub fn write_frames<S, B>(mut writer: impl Write, frames: &Frames<S>) -> io::Result<()>
+where
+    S: IterableBase<T = PauliStack<B>>,
+    B: BooleanVector,
+{
+    let storage = frames.as_storage();
+    writer.write_all(&MAGIC)?;
+    writer.write_all(&VERSION.to_le_bytes())?;
+    writer.write_all(&(storage.len() as u64).to_le_bytes())?;
+    writer.write_all(&(frames.frames_num() as u64).to_le_bytes())?;
+    for (qubit, stack) in storage.sort_by_key() {
+        writer.write_all(&(qubit as u64).to_le_bytes())?;
+        writer.write_all(&stack.canonical_bytes())?;
+    }
+    Ok(())
+}
+
+/// Read [Frames] in the stable [format](self#format) from the `reader`.
+///
+/// # Errors
+/// Errors if the `reader` fails or the data is not a valid encoding of [Frames], cf.
+/// [ReadError].
+///
+/// # Examples
+/// ```
+/// # fn main() { #![cfg_attr(coverage_nightly, coverage(off))]
+/// # use pauli_tracker::{collection::BufferedVector, io::{self, ReadError},
+/// #     pauli::PauliStack, tracker::frames::Frames};
+/// let error = io::read_frames::<BufferedVector<PauliStack<Vec<bool>>>, _>(
+///     b"PTFR\x02\x00\x00\x00".as_slice(),
+/// );
+/// assert!(matches!(error, Err(ReadError::Version(2))));
+/// # }
+/// ```
+pub fn read_frames<S, B>(mut reader: impl Read) -> Result<Frames<S>, ReadError>
+where
+    S: Base<TB = PauliStack<B>> + Default,
+    B: BooleanVector,
+{
+    let magic = read_array::<4>(&mut reader)?;
+    if magic != MAGIC {
+        return Err(ReadError::Magic(magic));
+    }
+    let version = u32::from_le_bytes(read_array(&mut reader)?);
+    if version == 0 || version > VERSION {
+        return Err(ReadError::Version(version));
+    }
+    let num_qubits = read_usize(&mut reader)?;
+    let frames_num = read_usize(&mut reader)?;
+
+    let sparse = supports_sparse::<S, B>();
+    let mut storage = S::default();
+    let mut previous = None;
+    for expected in 0..num_qubits {
+        let qubit = read_usize(&mut reader)?;
+        if let Some(previous) = previous {
+            if qubit <= previous {
+                return Err(ReadError::UnorderedQubit { qubit, previous });
+            }
+        }
+        if !sparse && qubit != expected {
+            return Err(ReadError::SparseQubit { qubit, expected });
+        }
+        previous = Some(qubit);
+        let z = read_bits(&mut reader, qubit, frames_num)?;
+        let x = read_bits(&mut reader, qubit, frames_num)?;
+        storage.insert(qubit, PauliStack { z, x });
+    }
+    Ok(Frames::new_unchecked(storage, frames_num))
+}
+
+// whether inserting a key into `S` doesn't fill up the missing keys (BufferedVector) or
+// ignores the key (NaiveVector); cheap, since `S` is empty
+fn supports_sparse<S, B>() -> bool
+where
+    S: Base<TB = PauliStack<B>> + Default,
+    B: BooleanVector,
+{
+    let mut probe = S::default();
+    probe.insert(1, PauliStack::default());
+    probe.len() == 1 && probe.get(1).is_some()
+}
+
+fn read_array<const N: usize>(reader: &mut impl Read) -> io::Result<[u8; N]> {
+    let mut bytes = [0; N];
+    reader.read_exact(&mut bytes)?;
+    Ok(bytes)
+}
+
+fn read_usize(reader: &mut impl Read) -> io::Result<usize> {
+    usize::try_from(u64::from_le_bytes(read_array(reader)?))
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+fn read_bits<B: BooleanVector>(
+    reader: &mut impl Read,
+    qubit: usize,
+    frames_num: usize,
+) -> Result<B, ReadError> {
+    let len = read_usize(reader)?;
+    if len != frames_num {
+        return Err(ReadError::StackLength { qubit, len, frames_num });
+    }
+    let num_bytes = len / 8 + usize::from(len % 8 != 0);
+    // don't trust `len` for the allocation; only read what is actually there
+    let mut bytes = Vec::new();
+    reader.take(num_bytes as u64).read_to_end(&mut bytes)?;
+    if bytes.len() != num_bytes {
+        return Err(io::Error::from(io::ErrorKind::UnexpectedEof).into());
+    }
+    Ok((0..len).map(|i| (bytes[i / 8] >> (i % 8)) & 1 == 1).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use coverage_helper::test;
+
+    use super::*;
+    use crate::{
+        collection::{BufferedVector, Init, Map, MappedVector, NaiveVector},
+        tracker::Tracker,
+    };
+
+    type ThisFrames = Frames<Map<PauliStack<Vec<bool>>>>;
+
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn frames() -> ThisFrames {
+        let mut frames = ThisFrames::init(3);
+        frames.track_x(0);
+        frames.cx(0, 2);
+        frames.track_y(1);
+        frames.track_z(2);
+        frames
+    }
+
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn write(frames: &ThisFrames) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        write_frames(&mut bytes, frames).unwrap();
+        bytes
+    }
+
+    #[test]
+    fn roundtrip() {
+        let frames = frames();
+        let bytes = write(&frames);
+        assert_eq!(read_frames::<Map<_>, _>(bytes.as_slice()).unwrap(), frames);
+        let other: Frames<MappedVector<PauliStack<bitvec::vec::BitVec>>> =
+            read_frames(bytes.as_slice()).unwrap();
+        assert!(frames.logically_eq(&other));
+        let mut other_bytes = Vec::new();
+        write_frames(&mut other_bytes, &other).unwrap();
+        assert_eq!(other_bytes, bytes);
+
+        let empty = ThisFrames::default();
+        assert_eq!(read_frames::<Map<_>, _>(write(&empty).as_slice()).unwrap(), empty);
+    }
+
+    #[test]
+    fn header_compatibility() {
+        // backward compatibility: this is the encoding of frames() in version 1, it has
+        // to stay readable
+        #[rustfmt::skip]
+        let version_1 = [
+            b'P', b'T', b'F', b'R', 1, 0, 0, 0, // magic, version
+            3, 0, 0, 0, 0, 0, 0, 0, // qubits
+            3, 0, 0, 0, 0, 0, 0, 0, // frames
+            0, 0, 0, 0, 0, 0, 0, 0, // qubit 0
+            3, 0, 0, 0, 0, 0, 0, 0, 0b000, // z
+            3, 0, 0, 0, 0, 0, 0, 0, 0b001, // x
+            1, 0, 0, 0, 0, 0, 0, 0, // qubit 1
+            3, 0, 0, 0, 0, 0, 0, 0, 0b010, // z
+            3, 0, 0, 0, 0, 0, 0, 0, 0b010, // x
+            2, 0, 0, 0, 0, 0, 0, 0, // qubit 2
+            3, 0, 0, 0, 0, 0, 0, 0, 0b100, // z
+            3, 0, 0, 0, 0, 0, 0, 0, 0b001, // x
+        ];
+        assert_eq!(read_frames::<Map<_>, _>(version_1.as_slice()).unwrap(), frames());
+        assert_eq!(write(&frames()), version_1);
+
+        // forward compatibility: newer versions are rejected, before reading anything
+        // else
+        let mut bytes = write(&frames());
+        bytes[4..8].copy_from_slice(&(VERSION + 1).to_le_bytes());
+        assert!(matches!(
+            read_frames::<Map<PauliStack<Vec<bool>>>, _>(bytes.as_slice()),
+            Err(ReadError::Version(v)) if v == VERSION + 1
+        ));
+    }
+
+    #[test]
+    fn invalid() {
+        #[cfg_attr(coverage_nightly, coverage(off))]
+        fn read(
+            bytes: &[u8],
+        ) -> Result<Frames<BufferedVector<PauliStack<Vec<bool>>>>, ReadError> {
+            read_frames(bytes)
+        }
+        let bytes = write(&frames());
+
+        let mut wrong_magic = bytes.clone();
+        wrong_magic[0] = b'Q';
+        assert!(matches!(read(&wrong_magic), Err(ReadError::Magic(m)) if &m == b"QTFR"));
+
+        assert!(matches!(
+            read(&bytes[..bytes.len() - 1]),
+            Err(ReadError::Io(e)) if e.kind() == io::ErrorKind::UnexpectedEof
+        ));
+
+        let mut too_few_frames = bytes.clone();
+        too_few_frames[16] = 2;
+        assert!(matches!(
+            read(&too_few_frames),
+            Err(ReadError::StackLength {
+                qubit: 0,
+                len: 3,
+                frames_num: 2
+            })
+        ));
+
+        // qubit 0: z with 2 frames, x with 3 frames
+        #[rustfmt::skip]
+        let mismatch = [
+            b'P', b'T', b'F', b'R', 1, 0, 0, 0, // magic, version
+            1, 0, 0, 0, 0, 0, 0, 0, // qubits
+            3, 0, 0, 0, 0, 0, 0, 0, // frames
+            0, 0, 0, 0, 0, 0, 0, 0, // qubit 0
+            2, 0, 0, 0, 0, 0, 0, 0, 0b01, // z
+            3, 0, 0, 0, 0, 0, 0, 0, 0b001, // x
+        ];
+        assert!(matches!(
+            read(&mismatch),
+            Err(ReadError::StackLength {
+                qubit: 0,
+                len: 2,
+                frames_num: 3
+            })
+        ));
+        let mut short_x = mismatch;
+        short_x[32] = 3; // z with 3 frames
+        short_x[41] = 1; // x with 1 frame
+        assert!(matches!(
+            read(&short_x),
+            Err(ReadError::StackLength {
+                qubit: 0,
+                len: 1,
+                frames_num: 3
+            })
+        ));
+
+        let mut version_0 = bytes.clone();
+        version_0[4] = 0;
+        assert!(matches!(read(&version_0), Err(ReadError::Version(0))));
+
+        let mut duplicate = bytes;
+        duplicate[8] = 4; // one more qubit
+        duplicate.extend_from_slice(&[1, 0, 0, 0, 0, 0, 0, 0]); // qubit 1
+        duplicate.extend_from_slice(&[0; 16]); // empty stack
+        assert!(matches!(
+            read(&duplicate),
+            Err(ReadError::UnorderedQubit { qubit: 1, previous: 2 })
+        ));
+    }
+
+    #[test]
+    fn crafted_lengths() {
+        #[rustfmt::skip]
+        let huge_stack = [
+            b'P', b'T', b'F', b'R', 1, 0, 0, 0, // magic, version
+            1, 0, 0, 0, 0, 0, 0, 0, // qubits
+            255, 255, 255, 255, 255, 255, 255, 255, // frames
+            0, 0, 0, 0, 0, 0, 0, 0, // qubit 0
+            255, 255, 255, 255, 255, 255, 255, 255, // z
+        ];
+        assert!(matches!(
+            read_frames::<BufferedVector<PauliStack<Vec<bool>>>, _>(huge_stack.as_slice()),
+            Err(ReadError::Io(e)) if e.kind() == io::ErrorKind::UnexpectedEof
+        ));
+
+        // a huge qubit index must not make dense storages allocate
+        #[rustfmt::skip]
+        let huge_qubit = [
+            b'P', b'T', b'F', b'R', 1, 0, 0, 0, // magic, version
+            1, 0, 0, 0, 0, 0, 0, 0, // qubits
+            0, 0, 0, 0, 0, 0, 0, 0, // frames
+            0, 0, 0, 0, 0, 0, 0, 16, // qubit 2^60
+            0, 0, 0, 0, 0, 0, 0, 0, // z
+            0, 0, 0, 0, 0, 0, 0, 0, // x
+        ];
+        assert!(matches!(
+            read_frames::<BufferedVector<PauliStack<Vec<bool>>>, _>(huge_qubit.as_slice()),
+            Err(ReadError::SparseQubit { qubit, expected: 0 }) if qubit == 1 << 60
+        ));
+        let frames =
+            read_frames::<Map<PauliStack<Vec<bool>>>, _>(huge_qubit.as_slice()).unwrap();
+        assert_eq!(frames.as_storage().len(), 1);
+        assert!(frames.as_storage().contains_key(&(1 << 60)));
+    }
+
+    #[test]
+    fn qubit_order() {
+        #[cfg_attr(coverage_nightly, coverage(off))]
+        fn entry(qubit: u8) -> impl Iterator<Item = u8> {
+            [qubit, 0, 0, 0, 0, 0, 0, 0].into_iter().chain([0; 16])
+        }
+        #[cfg_attr(coverage_nightly, coverage(off))]
+        fn file(qubits: &[u8]) -> Vec<u8> {
+            let mut bytes = b"PTFR\x01\x00\x00\x00".to_vec();
+            bytes.extend_from_slice(&(qubits.len() as u64).to_le_bytes());
+            bytes.extend_from_slice(&[0; 8]);
+            bytes.extend(qubits.iter().flat_map(|&q| entry(q)));
+            bytes
+        }
+
+        let unsorted = file(&[1, 0]);
+        assert!(matches!(
+            read_frames::<Map<PauliStack<Vec<bool>>>, _>(unsorted.as_slice()),
+            Err(ReadError::UnorderedQubit { qubit: 0, previous: 1 })
+        ));
+        let duplicate = file(&[0, 0]);
+        assert!(matches!(
+            read_frames::<BufferedVector<PauliStack<Vec<bool>>>, _>(duplicate.as_slice()),
+            Err(ReadError::UnorderedQubit { qubit: 0, previous: 0 })
+        ));
+
+        let sparse = file(&[0, 2]);
+        assert_eq!(
+            read_frames::<Map<PauliStack<Vec<bool>>>, _>(sparse.as_slice())
+                .unwrap()
+                .as_storage()
+                .len(),
+            2
+        );
+        assert!(matches!(
+            read_frames::<BufferedVector<PauliStack<Vec<bool>>>, _>(sparse.as_slice()),
+            Err(ReadError::SparseQubit { qubit: 2, expected: 1 })
+        ));
+        assert!(matches!(
+            read_frames::<NaiveVector<PauliStack<Vec<bool>>>, _>(sparse.as_slice()),
+            Err(ReadError::SparseQubit { qubit: 2, expected: 1 })
+        ));
+        assert!(
+            read_frames::<NaiveVector<PauliStack<Vec<bool>>>, _>(
+                file(&[0, 1]).as_slice()
+            )
+            .is_ok()
+        );
+    }
+}
@@ -76,8 +76,12 @@ pub(crate) mod clifford_helper;
 
 pub mod collection;
 
+pub mod io;
+
 pub mod pauli;
 
+pub mod prelude;
+
 mod slice_extension;
 
 pub mod tracker;
@@ -100,19 +104,39 @@ mod tests {
         normal::<boolean_vector::bitvec_simd::SimdBitVec>();
         normal::<circuit::DummyCircuit>();
         normal::<circuit::RandomMeasurementCircuit>();
+        normal::<circuit::ReplayCircuit>();
         normal::<circuit::TrackedCircuit<(), (), ()>>();
         normal::<collection::BufferedVector<()>>();
         normal::<collection::MappedVector<()>>();
+        normal::<pauli::InvalidByte>();
+        normal::<pauli::InvalidU8>();
         normal::<pauli::PauliDense>();
         normal::<pauli::PauliStack<()>>();
         normal::<pauli::PauliTuple>();
         normal::<pauli::stack::BitCharError>();
+        normal::<pauli::stack::PauliStackSlice<'static, ()>>();
         normal::<tracker::MissingBit>();
+        normal::<tracker::QubitError>();
+        normal::<tracker::TeeTracker<(), ()>>();
         normal::<tracker::frames::Frames<()>>();
+        normal::<tracker::frames::FramesCheckpoint>();
+        normal::<tracker::frames::FramesWithOrigins<()>>();
+        normal::<tracker::frames::induced_order::IncrementalOrder>();
+        normal::<tracker::frames::induced_order::GraphDiff>();
+        normal::<tracker::frames::induced_order::GraphMetrics>();
+        normal::<tracker::frames::InconsistentFrames>();
         normal::<tracker::frames::OverwriteStack<()>>();
+        normal::<tracker::half_frames::HalfFrames<()>>();
+        normal::<tracker::half_frames::MixingGate>();
         normal::<tracker::live::Live<()>>();
+        normal::<tracker::live::LiveWithHistory<()>>();
         // Enums
+        normal::<circuit::MeasurementBasis>();
+        normal::<io::ReadError>();
         normal::<pauli::PauliEnum>();
+        normal::<tracker::frames::DependencyError>();
+        normal::<tracker::frames::FrameShapeError>();
         normal::<tracker::frames::MoveError<()>>();
+        normal::<tracker::half_frames::Half>();
     }
 }
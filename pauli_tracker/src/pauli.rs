@@ -10,7 +10,8 @@ We provide three different representations of single Pauli operators:
 - [PauliDense]: The Pauli encoded into a single byte
 - [PauliEnum]: The Pauli described as an enum. This very similar to [PauliDense];
   internally, [PauliDense] uses binary operations like '&', '^', etc. and [PauliEnum] uses
-  a bunch of match statements.
+  a bunch of match statements. This means that the operations on [PauliDense] are
+  branchless, which is what one wants for constant-time or vectorizable code.
 
 It probably depends very much on the situation which representation is best. We haven't
 performed any good benchmarks (a simple (naive) benchmark shows that it is maybe best
@@ -22,6 +23,12 @@ needed, into [PauliEnum]). If needed one can easily create a custom type that im
 [Frames](crate::tracker::frames::Frames) tracker.
 */
 
+use std::mem;
+
+#[cfg(feature = "num-complex")]
+use num_complex::Complex64;
+use thiserror::Error;
+
 macro_rules! const_pauli {
     ($($name:ident,)*) => {$(
         /// Pauli
@@ -90,6 +97,26 @@ pub trait Pauli {
         self.add(other);
     }
 
+    /// Multiply `self` with `other` in place, like [multiply](Self::multiply), but
+    /// taking `other` by reference, so that no clone is needed for types that are not
+    /// [Copy].
+    ///
+    /// The default implementation uses [xpx](Self::xpx) and [zpz](Self::zpz).
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() { #![cfg_attr(coverage_nightly, coverage(off))]
+    /// # use pauli_tracker::pauli::{Pauli, PauliDense};
+    /// let mut pauli = PauliDense::new_x();
+    /// pauli.multiply_ref(&PauliDense::new_z());
+    /// assert_eq!(pauli, PauliDense::new_y());
+    /// # }
+    /// ```
+    fn multiply_ref(&mut self, other: &Self) {
+        self.xpx(other);
+        self.zpz(other);
+    }
+
     /// Add the `other` Pauli to `self` in place.
     #[deprecated(since = "0.4.2", note = "use `multiply` instead")]
     fn add(&mut self, other: Self);
@@ -145,6 +172,21 @@ pub trait Pauli {
     /// ```
     fn get_z(&self) -> bool;
 
+    /// Check whether the Pauli is Y (neglecting phases), i.e., whether it has both, an X
+    /// and a Z component.
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() { #![cfg_attr(coverage_nightly, coverage(off))]
+    /// # use pauli_tracker::pauli::{Pauli, PauliDense};
+    /// assert!(PauliDense::new_y().is_y());
+    /// assert!(!PauliDense::new_x().is_y());
+    /// # }
+    /// ```
+    fn is_y(&self) -> bool {
+        self.get_x() && self.get_z()
+    }
+
     /// Set whether the Pauli products contains X.
     ///
     /// # Examples
@@ -173,15 +215,50 @@ pub trait Pauli {
 
     /// Translate into the tableau encoding
     fn tableau_encoding(&self) -> u8;
+
+    /// Get the Pauli as 2x2 matrix, e.g., to verify the conjugation rules on the matrix
+    /// level. Since the phases are neglected, the Hermitian Pauli matrices are returned,
+    /// i.e., Y = iXZ.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[cfg_attr(coverage_nightly, coverage(off))]
+    /// # #[cfg(feature = "num-complex")]
+    /// # fn main() {
+    /// # use num_complex::Complex64;
+    /// # use pauli_tracker::pauli::{Pauli, PauliDense};
+    /// let (zero, i) = (Complex64::new(0., 0.), Complex64::new(0., 1.));
+    /// assert_eq!(PauliDense::new_y().to_matrix(), [[zero, -i], [i, zero]]);
+    /// # }
+    /// # #[cfg_attr(coverage_nightly, coverage(off))]
+    /// # #[cfg(not(feature = "num-complex"))]
+    /// # fn main() {}
+    /// ```
+    #[cfg(feature = "num-complex")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "num-complex")))]
+    fn to_matrix(&self) -> [[Complex64; 2]; 2] {
+        let zero = Complex64::new(0., 0.);
+        let one = Complex64::new(1., 0.);
+        let i = Complex64::new(0., 1.);
+        match (self.get_z(), self.get_x()) {
+            (false, false) => [[one, zero], [zero, one]],
+            (true, false) => [[one, zero], [zero, -one]],
+            (false, true) => [[zero, one], [one, zero]],
+            (true, true) => [[zero, -i], [i, zero]],
+        }
+    }
 }
 
 mod dense;
-pub use dense::PauliDense;
+pub use dense::{InvalidU8, PauliDense};
 mod enumlike;
 pub use enumlike::PauliEnum;
 mod tuple;
 pub use tuple::PauliTuple;
 
+#[cfg(feature = "arbitrary")]
+mod arbitrary_impls;
+
 impl From<PauliEnum> for PauliDense {
     fn from(pauli: PauliEnum) -> Self {
         // safety: discriminant follows the tableau encoding, so it is < 4
@@ -220,6 +297,146 @@ pub mod stack;
 #[doc(inline)]
 pub use stack::PauliStack;
 
+/// The number of two-qubit Clifford gates, up to Paulis and phases. Compare
+/// [conjugate_pair_by].
+pub const NUM_TWO_QUBIT_CLIFFORDS: u16 = 720;
+
+/// The Error when one tries to convert bytes into Paulis, but one of the bytes is not a
+/// valid [tableau encoding](tableau_encoding), as in [from_tableau_bytes].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Error)]
+#[error("invalid byte at position {position}: {source}")]
+pub struct InvalidByte {
+    /// The position of the invalid byte.
+    pub position: usize,
+    /// The invalid byte.
+    #[source]
+    pub source: InvalidU8,
+}
+
+/// Convert bytes in the [tableau encoding](tableau_encoding) into Paulis. This is the
+/// inverse of [to_tableau_bytes].
+///
+/// Errors if one of the bytes is larger than 3.
+///
+/// # Examples
+/// ```
+/// # fn main() { #![cfg_attr(coverage_nightly, coverage(off))]
+/// # use pauli_tracker::pauli::{self, Pauli, PauliTuple};
+/// assert_eq!(
+///     pauli::from_tableau_bytes(&[0, 1, 2, 3]),
+///     Ok(vec![PauliTuple::I, PauliTuple::Z, PauliTuple::X, PauliTuple::Y])
+/// );
+/// # }
+/// ```
+pub fn from_tableau_bytes(bytes: &[u8]) -> Result<Vec<PauliTuple>, InvalidByte> {
+    bytes
+        .iter()
+        .enumerate()
+        .map(|(position, &byte)| match PauliDense::try_from(byte) {
+            Ok(pauli) => Ok(pauli.into()),
+            Err(source) => Err(InvalidByte { position, source }),
+        })
+        .collect()
+}
+
+/// Convert Paulis into bytes in the [tableau encoding](tableau_encoding). This is the
+/// inverse of [from_tableau_bytes].
+///
+/// # Examples
+/// ```
+/// # fn main() { #![cfg_attr(coverage_nightly, coverage(off))]
+/// # use pauli_tracker::pauli::{self, Pauli, PauliTuple};
+/// assert_eq!(pauli::to_tableau_bytes(&[PauliTuple::Y, PauliTuple::I]), vec![3, 0]);
+/// # }
+/// ```
+pub fn to_tableau_bytes(paulis: &[PauliTuple]) -> Vec<u8> {
+    paulis.iter().map(Pauli::tableau_encoding).collect()
+}
+
+/// Conjugate the two Paulis `a` and `b` with the two-qubit Clifford gate that is
+/// enumerated by `clifford`, ignoring phases.
+///
+/// The enumeration follows the decomposition of the two-qubit Clifford group (up to
+/// Paulis and phases) into four classes, where C is one of the six single-qubit coset
+/// representatives I, S, H, SH, HS, SHS and R is one of I, SH, HS:
+/// - `0..36`: the single-qubit class, (C ⊗ C),
+/// - `36..360`: the CX-like class, (C ⊗ C) · CX · (R ⊗ R), where `a` is the control,
+/// - `360..684`: the iSWAP-like class, (C ⊗ C) · iSWAP · (R ⊗ R),
+/// - `684..720`: the SWAP-like class, (C ⊗ C) · SWAP.
+///
+/// Within a class, the index is decomposed in a mixed radix, with the C on `a` being
+/// the most significant digit and the R on `b` being the least significant digit, for
+/// example, `36 + 9 * (6 * 2 + 1) + 3 * 1 + 2` is (H ⊗ S) · CX · (SH ⊗ HS) (applied from
+/// right to left). In particular, `0`, `36`, `360` and `684` are the identity, CX, iSWAP and
+/// SWAP, respectively.
+///
+/// # Panics
+/// Panics if `clifford` >= [NUM_TWO_QUBIT_CLIFFORDS].
+///
+/// # Examples
+/// ```
+/// # fn main() { #![cfg_attr(coverage_nightly, coverage(off))]
+/// # use pauli_tracker::pauli::{self, Pauli, PauliDense};
+/// let (mut a, mut b) = (PauliDense::new_x(), PauliDense::new_i());
+/// pauli::conjugate_pair_by(&mut a, &mut b, 36); // CX with `a` as control
+/// assert_eq!((a, b), (PauliDense::new_x(), PauliDense::new_x()));
+/// # }
+/// ```
+pub fn conjugate_pair_by<P: Pauli>(a: &mut P, b: &mut P, clifford: u16) {
+    fn single<P: Pauli>(pauli: &mut P, representative: u16) {
+        match representative {
+            0 => pauli.id(),
+            1 => pauli.s(),
+            2 => pauli.h(),
+            3 => pauli.sh(),
+            4 => pauli.hs(),
+            5 => pauli.shs(),
+            _ => unreachable!(),
+        }
+    }
+    fn cyclic<P: Pauli>(pauli: &mut P, representative: u16) {
+        match representative {
+            0 => pauli.id(),
+            1 => pauli.sh(),
+            2 => pauli.hs(),
+            _ => unreachable!(),
+        }
+    }
+
+    assert!(
+        clifford < NUM_TWO_QUBIT_CLIFFORDS,
+        "there are only {NUM_TWO_QUBIT_CLIFFORDS} two-qubit Cliffords, got {clifford}"
+    );
+
+    let (singles, cyclics) = match clifford {
+        0..=35 => (clifford, 0),
+        36..=359 => ((clifford - 36) / 9, (clifford - 36) % 9),
+        360..=683 => ((clifford - 360) / 9, (clifford - 360) % 9),
+        _ => (clifford - 684, 0),
+    };
+
+    cyclic(a, cyclics / 3);
+    cyclic(b, cyclics % 3);
+    match clifford {
+        0..=35 => {},
+        36..=359 => {
+            // cf. Live's cx
+            b.xpx(a);
+            a.zpz(b);
+        },
+        360..=683 => {
+            // cf. Live's iswap
+            mem::swap(a, b);
+            let copy = a.get_x() ^ b.get_x();
+            a.set_z(a.get_z() ^ copy);
+            b.set_z(b.get_z() ^ copy);
+        },
+        _ => mem::swap(a, b),
+    }
+    single(a, singles / 6);
+    single(b, singles % 6);
+}
+
 /// Pauli encoding into two bits (ignoring phases).
 pub mod tableau_encoding {
     /// Code for the identity.
@@ -373,6 +590,112 @@ mod tests {
         check!();
     }
 
+    #[cfg(feature = "num-complex")]
+    #[test]
+    fn matrix_conjugation() {
+        type Matrix = [[Complex64; 2]; 2];
+        #[cfg_attr(coverage_nightly, coverage(off))]
+        fn mul(a: &Matrix, b: &Matrix) -> Matrix {
+            let mut res = [[Complex64::new(0., 0.); 2]; 2];
+            for (i, row) in res.iter_mut().enumerate() {
+                for (j, entry) in row.iter_mut().enumerate() {
+                    *entry = a[i][0] * b[0][j] + a[i][1] * b[1][j];
+                }
+            }
+            res
+        }
+        #[cfg_attr(coverage_nightly, coverage(off))]
+        fn dagger(a: &Matrix) -> Matrix {
+            [[a[0][0].conj(), a[1][0].conj()], [a[0][1].conj(), a[1][1].conj()]]
+        }
+        #[cfg_attr(coverage_nightly, coverage(off))]
+        fn equal_up_to_sign(a: &Matrix, b: &Matrix) -> bool {
+            let close = |sign: f64| {
+                a.iter()
+                    .flatten()
+                    .zip(b.iter().flatten())
+                    .all(|(a, b)| (a - sign * b).norm() < 1e-12)
+            };
+            close(1.) || close(-1.)
+        }
+
+        let r = std::f64::consts::FRAC_1_SQRT_2;
+        let h = [[Complex64::new(r, 0.), Complex64::new(r, 0.)], [
+            Complex64::new(r, 0.),
+            Complex64::new(-r, 0.),
+        ]];
+        let s = [[Complex64::new(1., 0.), Complex64::new(0., 0.)], [
+            Complex64::new(0., 0.),
+            Complex64::new(0., 1.),
+        ]];
+        for (gate, conjugate) in [
+            (h, &PauliDense::h as &dyn Fn(&mut PauliDense)),
+            (s, &PauliDense::s as &dyn Fn(&mut PauliDense)),
+        ] {
+            for mut pauli in [PauliDense::I, PauliDense::X, PauliDense::Y, PauliDense::Z]
+            {
+                let conjugated = mul(&mul(&gate, &pauli.to_matrix()), &dagger(&gate));
+                conjugate(&mut pauli);
+                assert!(equal_up_to_sign(&conjugated, &pauli.to_matrix()));
+            }
+        }
+    }
+
+    #[test]
+    fn dense_enum_agree() {
+        let paulis = [PauliEnum::I, PauliEnum::Z, PauliEnum::X, PauliEnum::Y];
+        #[rustfmt::skip]
+        let singles = [
+            (&PauliEnum::s   as &dyn Fn(&mut PauliEnum), &PauliDense::s as &dyn Fn(&mut PauliDense)),
+            (&PauliEnum::h   as &dyn Fn(&mut PauliEnum), &PauliDense::h as &dyn Fn(&mut PauliDense)),
+            (&PauliEnum::sh  as &dyn Fn(&mut PauliEnum), &PauliDense::sh as &dyn Fn(&mut PauliDense)),
+            (&PauliEnum::hs  as &dyn Fn(&mut PauliEnum), &PauliDense::hs as &dyn Fn(&mut PauliDense)),
+            (&PauliEnum::shs as &dyn Fn(&mut PauliEnum), &PauliDense::shs as &dyn Fn(&mut PauliDense)),
+        ];
+        #[rustfmt::skip]
+        let doubles = [
+            (&PauliEnum::zpz as &dyn Fn(&mut PauliEnum, &PauliEnum), &PauliDense::zpz as &dyn Fn(&mut PauliDense, &PauliDense)),
+            (&PauliEnum::zpx as &dyn Fn(&mut PauliEnum, &PauliEnum), &PauliDense::zpx as &dyn Fn(&mut PauliDense, &PauliDense)),
+            (&PauliEnum::xpz as &dyn Fn(&mut PauliEnum, &PauliEnum), &PauliDense::xpz as &dyn Fn(&mut PauliDense, &PauliDense)),
+            (&PauliEnum::xpx as &dyn Fn(&mut PauliEnum, &PauliEnum), &PauliDense::xpx as &dyn Fn(&mut PauliDense, &PauliDense)),
+        ];
+        for this in paulis {
+            for (enum_fun, dense_fun) in singles {
+                let (mut e, mut d) = (this, PauliDense::from(this));
+                enum_fun(&mut e);
+                dense_fun(&mut d);
+                assert_eq!(PauliDense::from(e), d);
+            }
+            for other in paulis {
+                for (enum_fun, dense_fun) in doubles {
+                    let (mut e, mut d) = (this, PauliDense::from(this));
+                    enum_fun(&mut e, &other);
+                    dense_fun(&mut d, &PauliDense::from(other));
+                    assert_eq!(PauliDense::from(e), d);
+                }
+                let (mut e, mut d) = (this, PauliDense::from(this));
+                e.multiply(other);
+                d.multiply(PauliDense::from(other));
+                assert_eq!(PauliDense::from(e), d);
+            }
+        }
+    }
+
+    #[test]
+    fn multiply_ref() {
+        fn check<T: PauliAssert>() {
+            for this in [T::I, T::Z, T::X, T::Y] {
+                for other in [T::I, T::Z, T::X, T::Y] {
+                    let (mut by_value, mut by_ref) = (this, this);
+                    by_value.multiply(other);
+                    by_ref.multiply_ref(&other);
+                    assert_eq!(by_value, by_ref);
+                }
+            }
+        }
+        check!();
+    }
+
     #[test]
     fn get() {
         fn check<T: PauliAssert>() {
@@ -392,6 +715,18 @@ mod tests {
         check!();
     }
 
+    #[test]
+    fn is_y() {
+        fn check<T: PauliAssert>() {
+            for (input, expected) in
+                [(T::I, false), (T::Z, false), (T::X, false), (T::Y, true)]
+            {
+                assert_eq!(input.is_y(), expected);
+            }
+        }
+        check!();
+    }
+
     #[test]
     fn set() {
         fn check<T: PauliAssert>() {
@@ -421,6 +756,78 @@ mod tests {
         check!();
     }
 
+    #[test]
+    fn tableau_bytes() {
+        let bytes = [3, 0, 1, 2, 2, 1, 3];
+        let paulis = from_tableau_bytes(&bytes).unwrap();
+        assert_eq!(paulis.len(), bytes.len());
+        assert_eq!(paulis[0], PauliTuple::Y);
+        assert_eq!(to_tableau_bytes(&paulis), bytes);
+
+        assert_eq!(
+            from_tableau_bytes(&[0, 1, 4, 2, 7]),
+            Err(InvalidByte {
+                position: 2,
+                source: InvalidU8(4)
+            })
+        );
+        assert_eq!(from_tableau_bytes(&[]), Ok(vec![]));
+    }
+
+    #[test]
+    fn two_qubit_cliffords() {
+        use crate::{
+            collection::{BufferedVector, Init},
+            tracker::{Tracker, live::Live},
+        };
+
+        let basis = [(1, 0), (0, 1), (2, 0), (0, 2)];
+        let conjugate = |clifford: u16, (a, b): (u8, u8)| {
+            let mut a = PauliDense::try_from(a).unwrap();
+            let mut b = PauliDense::try_from(b).unwrap();
+            conjugate_pair_by(&mut a, &mut b, clifford);
+            (a.storage(), b.storage())
+        };
+
+        // the class representatives, compared with the tracker implementation
+        for (clifford, gate) in [
+            (0, Live::id as fn(&mut Live<BufferedVector<PauliDense>>, usize)),
+            (36, |t, _| t.cx(0, 1)),
+            (360, |t, _| t.iswap(0, 1)),
+            (684, |t, _| t.swap(0, 1)),
+            // (H ⊗ S) · CX · (SH ⊗ HS)
+            (36 + 9 * 13 + 5, |t, _| {
+                t.sh(0);
+                t.hs(1);
+                t.cx(0, 1);
+                t.h(0);
+                t.s(1);
+            }),
+        ] {
+            for input in 0..16 {
+                let input = (input >> 2, input & 3);
+                let mut tracker = Live::<BufferedVector<PauliDense>>::init(2);
+                tracker.track_pauli_string(vec![
+                    (0, PauliDense::try_from(input.0).unwrap()),
+                    (1, PauliDense::try_from(input.1).unwrap()),
+                ]);
+                gate(&mut tracker, 0);
+                let expected = (
+                    tracker.get(0).unwrap().storage(),
+                    tracker.get(1).unwrap().storage(),
+                );
+                assert_eq!(conjugate(clifford, input), expected, "{clifford}: {input:?}");
+            }
+        }
+
+        // the enumeration is a bijection onto the two-qubit Cliffords (they are
+        // homomorphisms, so it is enough to compare the images of the basis)
+        let images = (0..NUM_TWO_QUBIT_CLIFFORDS)
+            .map(|clifford| basis.map(|input| conjugate(clifford, input)))
+            .collect::<std::collections::HashSet<_>>();
+        assert_eq!(images.len(), NUM_TWO_QUBIT_CLIFFORDS as usize);
+    }
+
     #[test]
     fn partial_add() {
         fn check<T: PauliAssert>() {
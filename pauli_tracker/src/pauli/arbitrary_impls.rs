@@ -0,0 +1,72 @@
+// implementations of arbitrary::Arbitrary for the Pauli types, for fuzzing
+
+use ::arbitrary::{Arbitrary, Result, Unstructured};
+
+use super::{Pauli, PauliDense, PauliEnum, PauliStack, PauliTuple};
+use crate::boolean_vector::BooleanVector;
+
+impl<'a> Arbitrary<'a> for PauliDense {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Self::try_from(u.int_in_range(0..=3)?)
+            .expect("int_in_range returned a value out of range"))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        u8::size_hint(depth)
+    }
+}
+
+impl<'a> Arbitrary<'a> for PauliEnum {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(PauliDense::arbitrary(u)?.into())
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        PauliDense::size_hint(depth)
+    }
+}
+
+impl<'a> Arbitrary<'a> for PauliTuple {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Self::new_product(bool::arbitrary(u)?, bool::arbitrary(u)?))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <(bool, bool)>::size_hint(depth)
+    }
+}
+
+/// The generated `z` and `x` parts have the same length.
+impl<'a, T: BooleanVector> Arbitrary<'a> for PauliStack<T> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        u.arbitrary_iter::<PauliTuple>()?.collect::<Result<_>>()
+    }
+
+    fn arbitrary_take_rest(u: Unstructured<'a>) -> Result<Self> {
+        u.arbitrary_take_rest_iter::<PauliTuple>()?.collect::<Result<_>>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use coverage_helper::test;
+
+    use super::*;
+
+    #[test]
+    fn invariants() {
+        let bytes = (0..=255).cycle().take(2000).collect::<Vec<u8>>();
+        let mut u = Unstructured::new(&bytes);
+        for _ in 0..100 {
+            assert!(PauliDense::arbitrary(&mut u).unwrap().storage() < 4);
+            PauliEnum::arbitrary(&mut u).unwrap();
+            PauliTuple::arbitrary(&mut u).unwrap();
+            let stack = PauliStack::<Vec<bool>>::arbitrary(&mut u).unwrap();
+            assert_eq!(stack.z.len(), stack.x.len());
+        }
+        let stack =
+            PauliStack::<Vec<bool>>::arbitrary_take_rest(Unstructured::new(&bytes))
+                .unwrap();
+        assert_eq!(stack.z.len(), stack.x.len());
+    }
+}
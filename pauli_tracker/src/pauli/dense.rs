@@ -12,6 +12,13 @@ use super::{Pauli, tableau_encoding};
 /// (inclusive). The encoding follows [tableau_encoding]. Compare
 /// [PauliEnum](super::PauliEnum) for a similar representation.
 ///
+/// The conjugations ([Pauli::s], [Pauli::h], ...), the (partial) multiplications and
+/// the setters are implemented with bit operations only, i.e., without any
+/// branching, in contrast to [PauliEnum](super::PauliEnum), which matches on its
+/// variants. While the compiler does not formally guarantee that it doesn't introduce
+/// branches, this makes [PauliDense] the recommended representation for code that
+/// should run in constant time or that should be vectorized.
+///
 /// Unsafe code might rely on that invariant (e.g., via accessing the storage with
 /// [Self::storage] and using it to index a pointer), therefore, functions that make it
 /// possible to circumvent the invariant are unsafe.
@@ -132,6 +139,10 @@ impl Pauli for PauliDense {
         self.xor(other);
     }
 
+    fn multiply_ref(&mut self, other: &Self) {
+        self.xor(*other);
+    }
+
     fn add(&mut self, other: Self) {
         self.multiply(other);
     }
@@ -198,6 +209,8 @@ impl Pauli for PauliDense {
 }
 
 use thiserror::Error;
+/// The Error when one tries to convert a [u8] that is not a valid [tableau
+/// encoding](super::tableau_encoding) into a Pauli.
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Error)]
 #[error("{0} is not between 0 and 3")]
 pub struct InvalidU8(pub u8);
@@ -69,6 +69,10 @@ impl Pauli for PauliEnum {
         }
     }
 
+    fn multiply_ref(&mut self, other: &Self) {
+        self.multiply(*other);
+    }
+
     fn add(&mut self, other: Self) {
         self.multiply(other);
     }
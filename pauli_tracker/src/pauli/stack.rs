@@ -2,10 +2,14 @@
   This module provides the [PauliStack] type, which stores multiple encoded Paulis.
 */
 
-use std::{cmp::Ordering, mem};
+use std::{
+    cmp::Ordering,
+    hash::{Hash, Hasher},
+    mem,
+    ops::{BitAnd, BitOr, Range},
+};
 
-#[cfg(feature = "serde")]
-use serde::{Deserialize, Serialize};
+use itertools::{EitherOrBoth, Itertools};
 use thiserror::Error;
 
 use super::{Pauli, PauliTuple};
@@ -18,18 +22,35 @@ use crate::boolean_vector::BooleanVector;
 /// (Clifford) operations on those [PauliStack]s.
 ///
 /// Note that the fields are public and the methods are mainly convenience methods.
+///
+/// # Serialization
+/// With the `serde` feature, the (de)serialization depends on the format: For
+/// human-readable formats, e.g., JSON, the stack is represented as a string of 'I', 'Z',
+/// 'X' and 'Y' characters, and for binary formats it is represented as a tuple of the
+/// number of Paulis and the Paulis packed into bytes, four Paulis per byte (in the
+/// [tableau encoding](super::tableau_encoding), with the first Pauli in the lowest two
+/// bits). Only stacks whose `z` and `x` parts have the same length can be serialized.
+/// For backwards compatibility, human-readable formats can still be deserialized from
+/// the old `{"z": ..., "x": ...}` representation.
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PauliStack<T /* : BooleanVector */> {
     /// The Z Pauli mask, i.e., the bits flagging whether there's a Z Pauli.
-    // backwards compatibility (at least when deserializing)
-    #[cfg_attr(feature = "serde", serde(alias = "right"))]
     pub z: T,
     /// The X Pauli mask, i.e., the bits flagging whether there's a X Pauli.
-    #[cfg_attr(feature = "serde", serde(alias = "left"))] // backwards comp....
     pub x: T,
 }
 
+/// A borrowed view into a range of frames of a [PauliStack], created with
+/// [PauliStack::frame_slice].
+///
+/// Comparing and hashing only considers the viewed Paulis, not the underlying stack or
+/// the position of the view in it.
+#[derive(Debug, Clone)]
+pub struct PauliStackSlice<'l, T> {
+    stack: &'l PauliStack<T>,
+    range: Range<usize>,
+}
+
 /// The Error when one tries to parse a char into a bool.
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Error)]
 #[error("'{chr}' is neither '0' nor '1'")]
@@ -82,6 +103,31 @@ impl<T: BooleanVector> PauliStack<T> {
         Self { z: zero.clone(), x: zero }
     }
 
+    /// Get the number of Paulis in the stack, i.e., the number of frames.
+    ///
+    /// If the [z](Self::z) and [x](Self::x) vectors have different lengths, the length of
+    /// the longer one is returned, consistent with [push](Self::push) and
+    /// [pop](Self::pop), which fill up the shorter one with `false/0`.
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() { #![cfg_attr(coverage_nightly, coverage(off))]
+    /// # use pauli_tracker::pauli::PauliStack;
+    /// let stack = PauliStack::<Vec<bool>>::try_from_str("100", "011").unwrap();
+    /// assert_eq!(stack.len(), 3);
+    /// let ragged = PauliStack::<Vec<bool>>::try_from_str("1", "011").unwrap();
+    /// assert_eq!(ragged.len(), 3);
+    /// # }
+    /// ```
+    pub fn len(&self) -> usize {
+        self.z.len().max(self.x.len())
+    }
+
+    /// Check whether the stack is empty, cf. [len](Self::len).
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
     /// Push a new [Pauli] onto the Pauli stack. If one part of the stack, i.e, `z`
     /// or `x`, is shorter than the other, it is filled up with `false/0` to have the
     /// same length, before the `pauli` is pushed.
@@ -178,6 +224,104 @@ impl<T: BooleanVector> PauliStack<T> {
         P::new_product(self.z.get(idx)?, self.x.get(idx)?).into()
     }
 
+    /// Check whether `self` and `other` are equal, up to trailing identities, i.e., when
+    /// the shorter stack is padded with identities. The `z` and `x` parts are compared
+    /// independently.
+    ///
+    /// This is useful to compare stacks that have been padded, e.g., with
+    /// [Frames::grow_frames_to](crate::tracker::frames::Frames::grow_frames_to).
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() { #![cfg_attr(coverage_nightly, coverage(off))]
+    /// # use pauli_tracker::pauli::PauliStack;
+    /// let stack = PauliStack::<Vec<bool>>::try_from_str("10", "01").unwrap();
+    /// let padded = PauliStack::try_from_str("1000", "0100").unwrap();
+    /// assert!(stack.eq_ignoring_trailing_identity(&padded));
+    /// assert!(!stack.eq_ignoring_trailing_identity(
+    ///     &PauliStack::try_from_str("1001", "0100").unwrap()
+    /// ));
+    /// # }
+    /// ```
+    pub fn eq_ignoring_trailing_identity(&self, other: &Self) -> bool {
+        fn eq<T: BooleanVector>(a: &T, b: &T) -> bool {
+            a.iter_vals().zip_longest(b.iter_vals()).all(|flags| match flags {
+                EitherOrBoth::Both(a, b) => a == b,
+                EitherOrBoth::Left(flag) | EitherOrBoth::Right(flag) => !flag,
+            })
+        }
+        eq(&self.z, &other.z) && eq(&self.x, &other.x)
+    }
+
+    /// Append the Paulis of `other` onto `self`. If one part of a stack, i.e., `z` or
+    /// `x`, is shorter than the other, it is filled up with `false/0` first, as in
+    /// [push](Self::push), so that the Paulis stay aligned.
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() { #![cfg_attr(coverage_nightly, coverage(off))]
+    /// # use pauli_tracker::pauli::PauliStack;
+    /// let mut pauli = PauliStack::<Vec<bool>>::try_from_str("10", "01").unwrap();
+    /// pauli.extend(&PauliStack::try_from_str("011", "110").unwrap());
+    /// assert_eq!(pauli, PauliStack::try_from_str("10011", "01110").unwrap());
+    ///
+    /// let mut ragged = PauliStack::<Vec<bool>>::try_from_str("1", "01").unwrap();
+    /// ragged.extend(&PauliStack::try_from_str("1", "").unwrap());
+    /// assert_eq!(ragged, PauliStack::try_from_str("101", "010").unwrap());
+    /// # }
+    /// ```
+    pub fn extend(&mut self, other: &Self) {
+        let len = self.z.len().max(self.x.len());
+        self.z.resize(len, false);
+        self.x.resize(len, false);
+        for flag in other.z.iter_vals() {
+            self.z.push(flag);
+        }
+        for flag in other.x.iter_vals() {
+            self.x.push(flag);
+        }
+        let len = len + other.z.len().max(other.x.len());
+        self.z.resize(len, false);
+        self.x.resize(len, false);
+    }
+
+    /// Get a borrowed view of the Paulis in `range`, without copying them. Compare
+    /// [frame_subrange](Self::frame_subrange).
+    ///
+    /// # Panics
+    /// Panics if `range` is not within both, the `z` and the `x` part of the stack.
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() { #![cfg_attr(coverage_nightly, coverage(off))]
+    /// # use pauli_tracker::pauli::{Pauli, PauliStack, PauliTuple};
+    /// let pauli = PauliStack::<Vec<bool>>::try_from_str("0110", "0011").unwrap();
+    /// let slice = pauli.frame_slice(1..3);
+    /// assert_eq!(slice.len(), 2);
+    /// assert_eq!(slice.get(0), Some(PauliTuple::Z));
+    /// assert_eq!(slice.get(1), Some(PauliTuple::Y));
+    /// assert_eq!(slice.get::<PauliTuple>(2), None);
+    /// # }
+    /// ```
+    pub fn frame_slice(&self, range: Range<usize>) -> PauliStackSlice<'_, T> {
+        assert!(
+            range.start <= range.end
+                && range.end <= self.z.len()
+                && range.end <= self.x.len(),
+            "range {range:?} out of bounds"
+        );
+        PauliStackSlice { stack: self, range }
+    }
+
+    /// Copy the Paulis in `range` into a new [PauliStack]. Compare
+    /// [frame_slice](Self::frame_slice).
+    ///
+    /// # Panics
+    /// Panics if `range` is not within both, the `z` and the `x` part of the stack.
+    pub fn frame_subrange(&self, range: Range<usize>) -> Self {
+        self.frame_slice(range).to_stack()
+    }
+
     /// Perform a bitwise XOR between the z and x stacks of `self` and `other`,
     /// respectively, updating `self` in place.
     pub fn xor_inplace(&mut self, other: &Self) {
@@ -185,6 +329,69 @@ impl<T: BooleanVector> PauliStack<T> {
         self.x.xor_inplace(&other.x);
     }
 
+    /// Perform a bitwise AND between the z and x stacks of `self` and `other`,
+    /// respectively, updating `self` in place.
+    pub fn and_inplace(&mut self, other: &Self) {
+        self.z.and_inplace(&other.z);
+        self.x.and_inplace(&other.x);
+    }
+
+    /// Perform a bitwise OR between the z and x stacks of `self` and `other`,
+    /// respectively, updating `self` in place.
+    pub fn or_inplace(&mut self, other: &Self) {
+        self.z.or_inplace(&other.z);
+        self.x.or_inplace(&other.x);
+    }
+
+    /// Get a mask of the frames that contain a Y, i.e., the elementwise AND of the
+    /// [z](Self::z) and [x](Self::x) vectors, e.g., to count the Ys.
+    ///
+    /// # Panics
+    /// Might panic if the [z](Self::z) and [x](Self::x) vectors have different lengths,
+    /// cf. [BooleanVector::and_inplace].
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() { #![cfg_attr(coverage_nightly, coverage(off))]
+    /// # use pauli_tracker::pauli::PauliStack;
+    /// let stack = PauliStack::<Vec<bool>>::try_from_str("1101", "0111").unwrap();
+    /// assert_eq!(stack.y_mask(), vec![false, true, false, true]);
+    /// # }
+    /// ```
+    pub fn y_mask(&self) -> T {
+        let mut mask = self.z.clone();
+        mask.and_inplace(&self.x);
+        mask
+    }
+
+    /// Get a copy of the stack with the [z](Self::z) and [x](Self::x) vectors swapped.
+    ///
+    /// This converts between this library's convention, where the [z](Self::z) vector is
+    /// the first one and the [x](Self::x) vector the second one (compare
+    /// [tableau_encoding](crate::pauli::tableau_encoding)), and tools that use the
+    /// opposite convention, e.g., via [PauliStack::try_from_str] with swapped arguments.
+    /// Physically, this is the same as conjugating with the Hadamard gate, cf.
+    /// [h](Self::h), which does the swap in place.
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() { #![cfg_attr(coverage_nightly, coverage(off))]
+    /// # use pauli_tracker::pauli::PauliStack;
+    /// // some tool that lists the X components first
+    /// let (x, z) = ("110", "011");
+    /// let foreign = PauliStack::<Vec<bool>>::try_from_str(x, z).unwrap();
+    /// let stack = foreign.swapped_xz();
+    /// assert_eq!(stack, PauliStack::try_from_str(z, x).unwrap());
+    /// assert_eq!(stack.swapped_xz(), foreign);
+    /// # }
+    /// ```
+    pub fn swapped_xz(&self) -> Self {
+        Self {
+            z: self.x.clone(),
+            x: self.z.clone(),
+        }
+    }
+
     // we can define the action of local gates
 
     /// Conjugate the Paulistack with the S gate ignoring phases.
@@ -212,6 +419,33 @@ impl<T: BooleanVector> PauliStack<T> {
         self.x.xor_inplace(&self.z);
     }
 
+    /// Conjugate only the Paulis in the frames `range` with the S gate ignoring phases,
+    /// cf. [BooleanVector::xor_range].
+    ///
+    /// # Panics
+    /// Panics if `range` is out of bounds.
+    pub fn s_range(&mut self, range: Range<usize>) {
+        self.z.xor_range(&self.x, range);
+    }
+    /// Conjugate only the Paulis in the frames `range` with the Hadamard gate ignoring
+    /// phases, cf. [BooleanVector::swap_range].
+    ///
+    /// # Panics
+    /// Panics if `range` is out of bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() { #![cfg_attr(coverage_nightly, coverage(off))]
+    /// # use pauli_tracker::pauli::PauliStack;
+    /// let mut stack = PauliStack::<Vec<bool>>::try_from_str("1100", "0011").unwrap();
+    /// stack.h_range(1..3);
+    /// assert_eq!(stack, PauliStack::try_from_str("1010", "0101").unwrap());
+    /// # }
+    /// ```
+    pub fn h_range(&mut self, range: Range<usize>) {
+        self.z.swap_range(&mut self.x, range);
+    }
+
     /// Multiply the Paulis, i.e., summing them up mod 2 in the tableau representation,
     /// with a `filter`, neglecting any phases. An element `e` is filtered if `filter[i]
     /// = true` where `i` is `e`'s index in [iter_vals](BooleanVector::iter_vals).
@@ -242,6 +476,48 @@ impl<T: BooleanVector> PauliStack<T> {
     pub fn sum_up(&self, filter: &[bool]) -> PauliTuple {
         PauliTuple::new_product(self.z.sum_up(filter), self.x.sum_up(filter))
     }
+
+    /// Return the approximate number of bytes that are allocated on the heap by the
+    /// [z](Self::z) and [x](Self::x) vectors, cf. [BooleanVector::capacity_bytes].
+    pub fn memory_bytes(&self) -> usize {
+        self.z.capacity_bytes() + self.x.capacity_bytes()
+    }
+
+    /// Get a canonical byte representation of the stack, which depends only on the
+    /// bits, and not on the [BooleanVector] type `T`.
+    ///
+    /// The derived [Hash] and [Eq] implementations depend on `T`, so to use stacks with
+    /// different `T`s as (hash) keys, use the canonical bytes as keys instead.
+    ///
+    /// The format is, for the [z](Self::z) and then the [x](Self::x) part, the length as
+    /// little-endian [u64] followed by the bits, packed into bytes with the first bit
+    /// in the least significant position.
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() { #![cfg_attr(coverage_nightly, coverage(off))]
+    /// # use pauli_tracker::pauli::PauliStack;
+    /// let stack = PauliStack::<Vec<bool>>::try_from_str("101", "1").unwrap();
+    /// assert_eq!(stack.canonical_bytes(), vec![
+    ///     3, 0, 0, 0, 0, 0, 0, 0, 0b101, 1, 0, 0, 0, 0, 0, 0, 0, 0b1
+    /// ]);
+    /// # }
+    /// ```
+    pub fn canonical_bytes(&self) -> Vec<u8> {
+        fn pack(vec: &impl BooleanVector, bytes: &mut Vec<u8>) {
+            bytes.extend_from_slice(&(vec.len() as u64).to_le_bytes());
+            let start = bytes.len();
+            bytes.resize(start + (vec.len() + 7) / 8, 0);
+            for (i, flag) in vec.iter_vals().enumerate() {
+                bytes[start + i / 8] |= (flag as u8) << (i % 8);
+            }
+        }
+        let mut bytes =
+            Vec::with_capacity(16 + (self.z.len() + 7) / 8 + (self.x.len() + 7) / 8);
+        pack(&self.z, &mut bytes);
+        pack(&self.x, &mut bytes);
+        bytes
+    }
 }
 
 impl<T: BooleanVector, P: Pauli> FromIterator<P> for PauliStack<T> {
@@ -253,3 +529,529 @@ impl<T: BooleanVector, P: Pauli> FromIterator<P> for PauliStack<T> {
         ret
     }
 }
+
+impl<T: BooleanVector> PauliStackSlice<'_, T> {
+    /// The number of Paulis in the view.
+    pub fn len(&self) -> usize {
+        self.range.len()
+    }
+
+    /// Check whether the view is empty.
+    pub fn is_empty(&self) -> bool {
+        self.range.is_empty()
+    }
+
+    /// Get the Pauli at index `idx`, relative to the start of the view; [None] if `idx`
+    /// is out of the view.
+    pub fn get<P: Pauli>(&self, idx: usize) -> Option<P> {
+        if idx >= self.len() {
+            return None;
+        }
+        self.stack.get(self.range.start + idx)
+    }
+
+    /// Iterate over the Paulis in the view.
+    pub fn iter<P: Pauli>(&self) -> impl Iterator<Item = P> + '_ {
+        self.range.clone().map(|idx| {
+            self.stack
+                .get(idx)
+                .expect("the range is checked when creating the view")
+        })
+    }
+
+    /// Copy the Paulis in the view into a new [PauliStack].
+    pub fn to_stack(&self) -> PauliStack<T> {
+        self.iter::<PauliTuple>().collect()
+    }
+}
+
+impl<T: BooleanVector> PartialEq for PauliStackSlice<'_, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len()
+            && self.iter::<PauliTuple>().eq(other.iter::<PauliTuple>())
+    }
+}
+
+impl<T: BooleanVector> Eq for PauliStackSlice<'_, T> {}
+
+impl<T: BooleanVector> Hash for PauliStackSlice<'_, T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.len().hash(state);
+        for pauli in self.iter::<PauliTuple>() {
+            pauli.hash(state);
+        }
+    }
+}
+
+/// The componentwise AND of the z and x stacks, cf.
+/// [and_inplace](PauliStack::and_inplace), e.g., to get the shared support of two
+/// stacks.
+///
+/// # Examples
+/// ```
+/// # fn main() { #![cfg_attr(coverage_nightly, coverage(off))]
+/// # use pauli_tracker::pauli::PauliStack;
+/// let a = PauliStack::<Vec<bool>>::try_from_str("1100", "0110").unwrap();
+/// let b = PauliStack::<Vec<bool>>::try_from_str("1010", "0011").unwrap();
+/// assert_eq!(a.clone() & b.clone(), PauliStack::try_from_str("1000", "0010").unwrap());
+/// assert_eq!(a | b, PauliStack::try_from_str("1110", "0111").unwrap());
+/// # }
+/// ```
+impl<T: BooleanVector> BitAnd for PauliStack<T> {
+    type Output = Self;
+    fn bitand(mut self, rhs: Self) -> Self {
+        self.and_inplace(&rhs);
+        self
+    }
+}
+
+/// The componentwise OR of the z and x stacks, cf.
+/// [or_inplace](PauliStack::or_inplace), e.g., to get the combined support of two
+/// stacks.
+impl<T: BooleanVector> BitOr for PauliStack<T> {
+    type Output = Self;
+    fn bitor(mut self, rhs: Self) -> Self {
+        self.or_inplace(&rhs);
+        self
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serialization {
+    use std::{fmt, marker::PhantomData};
+
+    use serde::{
+        Deserialize, Deserializer, Serialize, Serializer,
+        de::{self, MapAccess, SeqAccess, Visitor},
+        ser::{self, SerializeTuple},
+    };
+
+    use super::PauliStack;
+    use crate::{boolean_vector::BooleanVector, pauli::tableau_encoding};
+
+    const CHARS: [char; 4] = ['I', 'Z', 'X', 'Y'];
+
+    // the representation before we had the custom (de)serialization
+    #[derive(Deserialize)]
+    struct Legacy<T> {
+        // backwards compatibility (at least when deserializing)
+        #[serde(alias = "right")]
+        z: T,
+        #[serde(alias = "left")] // backwards comp....
+        x: T,
+    }
+
+    struct Bytes<'l>(&'l [u8]);
+
+    impl Serialize for Bytes<'_> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_bytes(self.0)
+        }
+    }
+
+    struct ByteBuf(Vec<u8>);
+
+    impl<'de> Deserialize<'de> for ByteBuf {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            struct ByteBufVisitor;
+            impl<'de> Visitor<'de> for ByteBufVisitor {
+                type Value = ByteBuf;
+                fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                    formatter.write_str("bytes")
+                }
+                fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<ByteBuf, E> {
+                    Ok(ByteBuf(v.to_vec()))
+                }
+                fn visit_byte_buf<E: de::Error>(self, v: Vec<u8>) -> Result<ByteBuf, E> {
+                    Ok(ByteBuf(v))
+                }
+                fn visit_seq<A: SeqAccess<'de>>(
+                    self,
+                    mut seq: A,
+                ) -> Result<ByteBuf, A::Error> {
+                    let mut ret = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+                    while let Some(byte) = seq.next_element()? {
+                        ret.push(byte);
+                    }
+                    Ok(ByteBuf(ret))
+                }
+            }
+            deserializer.deserialize_byte_buf(ByteBufVisitor)
+        }
+    }
+
+    fn encode(z: bool, x: bool) -> u8 {
+        ((x as u8) << 1) | z as u8
+    }
+
+    fn decode(pauli: u8) -> (bool, bool) {
+        (pauli & tableau_encoding::Z != 0, pauli & tableau_encoding::X != 0)
+    }
+
+    impl<T: BooleanVector> Serialize for PauliStack<T> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let len = self.z.len();
+            if len != self.x.len() {
+                return Err(ser::Error::custom(format!(
+                    "the z and x parts of the PauliStack have different lengths ({} and \
+                     {})",
+                    len,
+                    self.x.len()
+                )));
+            }
+            let paulis = self.z.iter_vals().zip(self.x.iter_vals());
+            if serializer.is_human_readable() {
+                serializer.collect_str(
+                    &paulis
+                        .map(|(z, x)| CHARS[encode(z, x) as usize])
+                        .collect::<String>(),
+                )
+            } else {
+                let mut bytes = vec![0; (len + 3) / 4];
+                for (i, (z, x)) in paulis.enumerate() {
+                    bytes[i / 4] |= encode(z, x) << (2 * (i % 4));
+                }
+                let mut tuple = serializer.serialize_tuple(2)?;
+                tuple.serialize_element(&(len as u64))?;
+                tuple.serialize_element(&Bytes(&bytes))?;
+                tuple.end()
+            }
+        }
+    }
+
+    struct StackVisitor<T>(PhantomData<T>);
+
+    impl<'de, T: BooleanVector + Deserialize<'de>> Visitor<'de> for StackVisitor<T> {
+        type Value = PauliStack<T>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str(
+                "a string of 'I', 'Z', 'X' and 'Y' characters, a (length, bytes) tuple \
+                 or a struct with 'z' and 'x' fields",
+            )
+        }
+
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+            v.chars()
+                .map(|c| match CHARS.iter().position(|&p| p == c) {
+                    Some(pauli) => Ok(decode(pauli as u8)),
+                    None => Err(E::invalid_value(de::Unexpected::Char(c), &self)),
+                })
+                .collect::<Result<Vec<_>, _>>()
+                .map(|paulis| PauliStack {
+                    z: paulis.iter().map(|&(z, _)| z).collect(),
+                    x: paulis.iter().map(|&(_, x)| x).collect(),
+                })
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(
+            self,
+            mut seq: A,
+        ) -> Result<Self::Value, A::Error> {
+            let len = seq
+                .next_element::<u64>()?
+                .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+            let bytes = seq
+                .next_element::<ByteBuf>()?
+                .ok_or_else(|| de::Error::invalid_length(1, &self))?
+                .0;
+            // the length is untrusted, so check it before allocating anything
+            let len = match usize::try_from(len).ok().filter(
+                |len| matches!(len.checked_add(3), Some(num) if num / 4 == bytes.len()),
+            ) {
+                Some(len) => len,
+                None => {
+                    return Err(de::Error::invalid_length(
+                        bytes.len(),
+                        &"(length + 3) / 4 bytes",
+                    ));
+                },
+            };
+            let mut ret = PauliStack::<T>::zeros(len);
+            for i in 0..len {
+                let (z, x) = decode((bytes[i / 4] >> (2 * (i % 4))) & 3);
+                ret.z.set(i, z);
+                ret.x.set(i, x);
+            }
+            Ok(ret)
+        }
+
+        fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> {
+            let Legacy { z, x } =
+                Legacy::deserialize(de::value::MapAccessDeserializer::new(map))?;
+            Ok(PauliStack { z, x })
+        }
+    }
+
+    impl<'de, T: BooleanVector + Deserialize<'de>> Deserialize<'de> for PauliStack<T> {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            if deserializer.is_human_readable() {
+                deserializer.deserialize_any(StackVisitor(PhantomData))
+            } else {
+                deserializer.deserialize_tuple(2, StackVisitor(PhantomData))
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use coverage_helper::test;
+
+        use super::*;
+        use crate::pauli::{Pauli, PauliTuple};
+
+        #[cfg_attr(coverage_nightly, coverage(off))]
+        fn stack() -> PauliStack<Vec<bool>> {
+            [
+                PauliTuple::new_x(),
+                PauliTuple::new_y(),
+                PauliTuple::new_z(),
+                PauliTuple::new_i(),
+                PauliTuple::new_y(),
+            ]
+            .into_iter()
+            .collect()
+        }
+
+        #[test]
+        fn json() {
+            let stack = stack();
+            let json = serde_json::to_string(&stack).unwrap();
+            assert_eq!(json, r#""XYZIY""#);
+            assert_eq!(
+                serde_json::from_str::<PauliStack<Vec<bool>>>(&json).unwrap(),
+                stack
+            );
+            assert_eq!(
+                serde_json::from_str::<PauliStack<Vec<bool>>>(
+                    r#"{"z":[false,true,true,false,true],"x":[true,true,false,false,true]}"#
+                )
+                .unwrap(),
+                stack
+            );
+            assert!(serde_json::from_str::<PauliStack<Vec<bool>>>(r#""XA""#).is_err());
+            assert!(
+                serde_json::to_string(&PauliStack { z: vec![true], x: vec![] }).is_err()
+            );
+        }
+
+        #[test]
+        fn binary() {
+            let stack = stack();
+            let bytes = bincode::serialize(&stack).unwrap();
+            // u64 length + u64 length prefix of the bytes + 2 bytes
+            assert_eq!(bytes.len(), 8 + 8 + 2);
+            assert_eq!(
+                bincode::deserialize::<PauliStack<Vec<bool>>>(&bytes).unwrap(),
+                stack
+            );
+            let empty = PauliStack::<Vec<bool>>::new();
+            assert_eq!(
+                bincode::deserialize::<PauliStack<Vec<bool>>>(
+                    &bincode::serialize(&empty).unwrap()
+                )
+                .unwrap(),
+                empty
+            );
+
+            // bogus lengths, u64::MAX would overflow
+            for len in [u64::MAX, 21] {
+                let mut bytes = len.to_le_bytes().to_vec();
+                bytes.extend_from_slice(&[0; 8]); // no bytes
+                assert!(bincode::deserialize::<PauliStack<Vec<bool>>>(&bytes).is_err());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use coverage_helper::test;
+
+    use super::*;
+
+    #[test]
+    fn len() {
+        let mut stack = PauliStack::<Vec<bool>>::new();
+        assert!(stack.is_empty());
+        stack.push(PauliTuple::new_x());
+        stack.push(PauliTuple::new_y());
+        stack.push(PauliTuple::new_i());
+        assert_eq!(stack.len(), 3);
+        assert!(!stack.is_empty());
+        stack.pop::<PauliTuple>();
+        assert_eq!(stack.len(), 2);
+        stack.pop::<PauliTuple>();
+        stack.pop::<PauliTuple>();
+        assert_eq!(stack.len(), 0);
+        assert!(stack.is_empty());
+        assert_eq!(PauliStack::<bit_vec::BitVec>::zeros(7).len(), 7);
+    }
+
+    #[test]
+    fn push_pop_different_pauli_types() {
+        use crate::pauli::{PauliDense, PauliEnum};
+        let mut stack = PauliStack::<Vec<bool>>::new();
+        stack.push(PauliEnum::Y);
+        stack.push(PauliDense::new_z());
+        stack.push(PauliTuple::new_x());
+        assert_eq!(stack.pop(), Some(PauliEnum::X));
+        assert_eq!(stack.pop(), Some(PauliTuple::new_z()));
+        assert_eq!(stack.pop(), Some(PauliDense::new_y()));
+        assert_eq!(stack.pop::<PauliDense>(), None);
+    }
+
+    #[test]
+    fn eq_ignoring_trailing_identity() {
+        let stack = PauliStack::<Vec<bool>>::try_from_str("0110", "1100").unwrap();
+        let mut padded = stack.clone();
+        for _ in 0..3 {
+            padded.push(PauliTuple::new_i());
+        }
+        assert_ne!(stack, padded);
+        assert!(stack.eq_ignoring_trailing_identity(&padded));
+        assert!(padded.eq_ignoring_trailing_identity(&stack));
+        assert!(stack.eq_ignoring_trailing_identity(&stack));
+
+        padded.push(PauliTuple::new_z());
+        assert!(!stack.eq_ignoring_trailing_identity(&padded));
+        let mut other = stack.clone();
+        other.x.set(0, false);
+        assert!(!stack.eq_ignoring_trailing_identity(&other));
+
+        // only the z part is padded, e.g., after Tracker::remove_x
+        let mut removed = stack.clone();
+        removed.x.resize(0, false);
+        assert!(removed.eq_ignoring_trailing_identity(
+            &PauliStack::try_from_str("0110", "").unwrap()
+        ));
+        assert!(
+            PauliStack::<Vec<bool>>::new()
+                .eq_ignoring_trailing_identity(&PauliStack::zeros(5))
+        );
+    }
+
+    #[test]
+    fn frame_range() {
+        let stack = PauliStack::<Vec<bool>>::try_from_str("10110", "01101").unwrap();
+        let slice = stack.frame_slice(1..3);
+        assert_eq!(slice.iter::<PauliTuple>().collect::<Vec<_>>(), vec![
+            PauliTuple::new_x(),
+            PauliTuple::new_y()
+        ]);
+        assert_eq!(slice.to_stack(), PauliStack::try_from_str("01", "11").unwrap());
+        assert_eq!(stack.frame_subrange(1..3), slice.to_stack());
+        assert!(stack.frame_slice(5..5).is_empty());
+
+        // same Paulis, different stacks and offsets
+        let other = PauliStack::<Vec<bool>>::try_from_str("01", "11").unwrap();
+        assert_eq!(slice, other.frame_slice(0..2));
+        assert_ne!(slice, stack.frame_slice(0..2));
+        assert_eq!(stack.frame_slice(0..0), other.frame_slice(1..1));
+        let set = std::collections::HashSet::from([slice, other.frame_slice(0..2)]);
+        assert_eq!(set.len(), 1);
+    }
+
+    #[test]
+    fn concatenation() {
+        let a = PauliStack::<Vec<bool>>::try_from_str("1101", "0111").unwrap();
+        let b = PauliStack::<Vec<bool>>::try_from_str("00", "10").unwrap();
+        let combined = PauliStack::<Vec<bool>>::try_from_str("110100", "011110").unwrap();
+        let mut extended = a.clone();
+        extended.extend(&b);
+        assert_eq!(extended, combined);
+        let mut extended = a.clone();
+        extended.extend(&PauliStack::new());
+        assert_eq!(extended, a);
+
+        // ragged stacks are padded, so that the Paulis stay aligned
+        let mut ragged = PauliStack::<Vec<bool>>::try_from_str("11", "0").unwrap();
+        ragged.extend(&PauliStack::try_from_str("", "101").unwrap());
+        assert_eq!(ragged, PauliStack::try_from_str("11000", "00101").unwrap());
+        let mut ragged = PauliStack::<Vec<bool>>::try_from_str("", "1").unwrap();
+        ragged.extend(&PauliStack::try_from_str("01", "").unwrap());
+        ragged.extend(&PauliStack::try_from_str("1", "1").unwrap());
+        assert_eq!(ragged, PauliStack::try_from_str("0011", "1001").unwrap());
+    }
+
+    #[test]
+    fn gates_on_range() {
+        let stack = PauliStack::<Vec<bool>>::try_from_str(
+            "100110101110010011010110010110",
+            "011011100101101100101001110011",
+        )
+        .unwrap();
+        let split = |stack: &PauliStack<Vec<bool>>| {
+            (
+                stack.frame_subrange(0..10),
+                stack.frame_subrange(10..20),
+                stack.frame_subrange(20..30),
+            )
+        };
+
+        for (ranged, full) in [
+            (
+                PauliStack::h_range as fn(&mut PauliStack<Vec<bool>>, Range<usize>),
+                PauliStack::h as fn(&mut PauliStack<Vec<bool>>),
+            ),
+            (PauliStack::s_range, PauliStack::s),
+        ] {
+            let mut applied = stack.clone();
+            ranged(&mut applied, 10..20);
+            let (mut front, mut middle, back) = split(&stack);
+            full(&mut middle);
+            front.extend(&middle);
+            front.extend(&back);
+            assert_eq!(applied, front);
+        }
+    }
+
+    #[test]
+    fn y_mask() {
+        type B = bit_vec::BitVec;
+        let (z, x) = ("1011001110", "0110101101");
+        let expected = B::from_ones(10, [2, 6, 7]);
+        assert_eq!(PauliStack::<B>::try_from_str(z, x).unwrap().y_mask(), expected);
+        assert_eq!(
+            PauliStack::<Vec<bool>>::try_from_str(z, x).unwrap().y_mask(),
+            expected.iter_vals().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            PauliStack::<bitvec::vec::BitVec>::try_from_str(z, x)
+                .unwrap()
+                .y_mask()
+                .iter_vals()
+                .collect::<B>(),
+            expected
+        );
+    }
+
+    #[test]
+    fn canonical_bytes() {
+        let (z, x) = ("1011001110", "0110101");
+        let stack = PauliStack::<Vec<bool>>::try_from_str(z, x).unwrap();
+        let bytes = stack.canonical_bytes();
+        assert_eq!(
+            PauliStack::<bitvec::vec::BitVec>::try_from_str(z, x)
+                .unwrap()
+                .canonical_bytes(),
+            bytes
+        );
+        assert_eq!(
+            PauliStack::<bit_vec::BitVec>::try_from_str(z, x)
+                .unwrap()
+                .canonical_bytes(),
+            bytes
+        );
+        assert_ne!(
+            PauliStack::<Vec<bool>>::try_from_str(x, z).unwrap().canonical_bytes(),
+            bytes
+        );
+        assert_ne!(
+            PauliStack::<Vec<bool>>::try_from_str("10110011100", x)
+                .unwrap()
+                .canonical_bytes(),
+            bytes
+        );
+    }
+}
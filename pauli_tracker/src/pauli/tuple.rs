@@ -51,6 +51,11 @@ impl Pauli for PauliTuple {
         self.1 ^= other.1;
     }
 
+    fn multiply_ref(&mut self, other: &Self) {
+        self.0 ^= other.0;
+        self.1 ^= other.1;
+    }
+
     fn add(&mut self, other: Self) {
         self.multiply(other);
     }
@@ -0,0 +1,67 @@
+/*!
+Re-exports of the commonly used traits and types.
+
+The traits are needed to use most of the library's functionality and the types are the
+ones that are usually needed to define a tracker. Glob import it to get everything at
+once:
+
+```
+# #[cfg_attr(coverage_nightly, coverage(off))]
+# fn main() {
+use pauli_tracker::prelude::*;
+
+// the "A first idea" example from the crate documentation
+type Track = Live<Map<PauliEnum>>;
+
+let mut tracker = Track::init(3);
+assert_eq!(
+    tracker.as_storage(),
+    &Map::from_iter([(0, PauliEnum::I), (1, PauliEnum::I), (2, PauliEnum::I)])
+);
+
+tracker.track_x(0);
+tracker.track_z(1);
+assert_eq!(
+    tracker.as_storage(),
+    &Map::from_iter([(0, PauliEnum::X), (1, PauliEnum::Z), (2, PauliEnum::I)])
+);
+
+tracker.cx(2, 1);
+tracker.cx(0, 1);
+tracker.s(0);
+assert_eq!(
+    tracker.as_storage(),
+    &Map::from_iter([(0, PauliEnum::X), (1, PauliEnum::Y), (2, PauliEnum::Z)])
+);
+
+tracker.new_qubit(3);
+tracker.new_qubit(5);
+assert_eq!(
+    tracker.as_storage(),
+    &Map::from_iter([
+        (0, PauliEnum::X),
+        (1, PauliEnum::Y),
+        (2, PauliEnum::Z),
+        (3, PauliEnum::I),
+        (5, PauliEnum::I)
+    ])
+);
+
+// and the Frames tracker
+let mut frames = Frames::<BufferedVector<PauliStack<Vec<bool>>>>::init(2);
+frames.track_x(0);
+frames.cx(0, 1);
+assert_eq!(frames.get_frame(0), Some(vec![(0, PauliTuple::X), (1, PauliTuple::X)]));
+# }
+```
+*/
+
+pub use crate::{
+    boolean_vector::BooleanVector,
+    collection::{
+        Base, BufferedVector, Full, Init, Iterable, IterableBase, Map, MappedVector,
+        NaiveVector,
+    },
+    pauli::{Pauli, PauliDense, PauliEnum, PauliStack, PauliTuple},
+    tracker::{PauliString, Tracker, frames::Frames, live::Live},
+};
@@ -17,9 +17,14 @@ non-deterministic side effects.
 [MBQC]: https://doi.org/10.48550/arXiv.0910.1116
 */
 
+use std::fmt;
+
 use thiserror::Error;
 
-use crate::{clifford_helper, pauli::Pauli};
+use crate::{
+    clifford_helper,
+    pauli::{InvalidU8, Pauli, PauliDense},
+};
 
 /// A vector describing an encoded Pauli string.
 ///
@@ -28,11 +33,82 @@ use crate::{clifford_helper, pauli::Pauli};
 /// index of the `Pauli`.
 pub type PauliString<T> = Vec<(usize, T)>;
 
+/// Convert the Paulis of a [PauliString] into another Pauli representation, using the
+/// [From] implementations between the representations.
+///
+/// # Examples
+/// ```
+/// # fn main() { #![cfg_attr(coverage_nightly, coverage(off))]
+/// # use pauli_tracker::{pauli::{Pauli, PauliDense, PauliTuple},
+/// #     tracker::{PauliString, convert_pauli_string}};
+/// let dense: PauliString<PauliDense> = vec![(0, PauliDense::X), (3, PauliDense::Y)];
+/// assert_eq!(convert_pauli_string::<_, PauliTuple>(dense), vec![
+///     (0, PauliTuple::X),
+///     (3, PauliTuple::Y)
+/// ]);
+/// # }
+/// ```
+pub fn convert_pauli_string<A: Into<B>, B>(string: PauliString<A>) -> PauliString<B> {
+    string.into_iter().map(|(bit, pauli)| (bit, pauli.into())).collect()
+}
+
+// the number of characters of the storage's Debug representation above which the Debug
+// implementations of the trackers elide the storage (unless the alternate flag is used);
+// measuring the representation, instead of counting the Paulis, doesn't require any
+// bounds on the storage beyond Debug
+const DEBUG_SUMMARY_THRESHOLD: usize = 10_000;
+
+// the Debug representation of `value`, or None if it is longer than the threshold; the
+// formatting is aborted as soon as the threshold is exceeded
+fn debug_below_threshold(value: &impl fmt::Debug) -> Option<String> {
+    struct Bounded(String);
+    impl fmt::Write for Bounded {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            if self.0.len() + s.len() > DEBUG_SUMMARY_THRESHOLD {
+                return Err(fmt::Error);
+            }
+            self.0.push_str(s);
+            Ok(())
+        }
+    }
+    let mut bounded = Bounded(String::new());
+    fmt::write(&mut bounded, format_args!("{value:?}")).ok()?;
+    Some(bounded.0)
+}
+
+// format the storage field of a tracker, cf. DEBUG_SUMMARY_THRESHOLD
+fn debug_storage(
+    debug: &mut fmt::DebugStruct<'_, '_>,
+    storage: &impl fmt::Debug,
+    alternate: bool,
+) {
+    if alternate {
+        debug.field("storage", storage);
+    } else {
+        match debug_below_threshold(storage) {
+            Some(storage) => debug.field("storage", &format_args!("{storage}")),
+            None => debug.field("storage", &format_args!("..")),
+        };
+    }
+}
+
 /// The Error when one tries to [measure](Tracker::measure) a missing bit.
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Error)]
 #[error("there's no Pauli stack for qubit {0}")]
 pub struct MissingBit(pub usize);
 
+/// The Error when one tries to apply a two-qubit gate on invalid qubits with one of the
+/// `try_*` methods of [Tracker], e.g., [try_cx](Tracker::try_cx).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Error)]
+pub enum QubitError {
+    /// One of the qubits is not present.
+    #[error(transparent)]
+    MissingBit(#[from] MissingBit),
+    /// Both qubits are the same qubit.
+    #[error("the gate acts twice on qubit {0}")]
+    SameQubit(usize),
+}
+
 macro_rules! single_doc_standard {
     ($gate:literal) => {
         concat!(
@@ -119,6 +195,58 @@ macro_rules! coset {
     )*};
 }
 
+macro_rules! try_gates {
+    () => {
+        try_gates!(
+            @single
+            (try_id, id), (try_x, x), (try_y, y), (try_z, z),
+            (try_s, s), (try_sdg, sdg), (try_sz, sz), (try_szdg, szdg), (try_hxy, hxy),
+            (try_h, h), (try_sy, sy), (try_sydg, sydg),
+            (try_sh, sh), (try_hs, hs),
+            (try_shs, shs), (try_sx, sx), (try_sxdg, sxdg), (try_hyz, hyz),
+            (try_v, v), (try_vdg, vdg),
+        );
+        try_gates!(
+            @double
+            (try_cz, cz), (try_cx, cx), (try_cy, cy), (try_swap, swap), (try_zcz, zcz),
+            (try_zcx, zcx), (try_zcy, zcy), (try_iswap, iswap), (try_iswapdg, iswapdg),
+        );
+    };
+    (@single $(($name:ident, $gate:ident),)*) => {$(
+        #[doc = concat!(
+            "Like [", stringify!($gate), "](Self::", stringify!($gate), "), but errors ",
+            "with [MissingBit] instead of panicking if the qu`bit` is not present, cf. ",
+            "[contains_qubit](Self::contains_qubit)."
+        )]
+        fn $name(&mut self, bit: usize) -> Result<(), MissingBit> {
+            if !self.contains_qubit(bit) {
+                return Err(MissingBit(bit));
+            }
+            self.$gate(bit);
+            Ok(())
+        }
+    )*};
+    (@double $(($name:ident, $gate:ident),)*) => {$(
+        #[doc = concat!(
+            "Like [", stringify!($gate), "](Self::", stringify!($gate), "), but errors ",
+            "with [QubitError] instead of panicking if the two qubits are the same or ",
+            "if one of them is not present, cf. [contains_qubit](Self::contains_qubit)."
+        )]
+        fn $name(&mut self, bit_a: usize, bit_b: usize) -> Result<(), QubitError> {
+            if bit_a == bit_b {
+                return Err(QubitError::SameQubit(bit_a));
+            }
+            for bit in [bit_a, bit_b] {
+                if !self.contains_qubit(bit) {
+                    return Err(MissingBit(bit).into());
+                }
+            }
+            self.$gate(bit_a, bit_b);
+            Ok(())
+        }
+    )*};
+}
+
 /// The core API to track Paulis through a Clifford circuit.
 ///
 /// The implementors must ensure that they implement the methods correctly according
@@ -167,10 +295,31 @@ pub trait Tracker {
     /// compatible with [Self::Stack].
     type Pauli: Pauli;
 
+    /// The names of the gates that are implemented directly, i.e., that do not use the
+    /// default implementations in terms of the generators[^generators]. This can be
+    /// used to prefer gates that have efficient implementations.
+    ///
+    /// The default is only the generators, i.e., `["s", "h", "cz"]`; implementors that
+    /// overwrite more gates should overwrite this constant accordingly.
+    const NATIVE_GATES: &'static [&'static str] = &["s", "h", "cz"];
+
     /// Insert a new qu`bit` into the tracker. If the qu`bit` is already present, the old
     /// value is overwritten and returned.
     fn new_qubit(&mut self, bit: usize) -> Option<Self::Stack>;
 
+    /// Check whether the qu`bit` is present in the tracker, e.g., to check the qubits
+    /// before applying a gate, as in the `try_*` methods, e.g., [try_h](Self::try_h).
+    ///
+    /// All trackers in this crate implement this method; the default implementation
+    /// exists only to not make a major breaking change in this trait and panics.
+    #[allow(unused_variables)]
+    fn contains_qubit(&self, bit: usize) -> bool {
+        panic!(
+            "the default implementation exists only to not make a major breaking change \
+             in this trait"
+        );
+    }
+
     /// Track a new frame consisting of the Pauli gate `pauli` at qu`bit`.
     ///
     /// If qu`bit` is not tracked, the method does not error, but simply tracks an empty
@@ -181,10 +330,57 @@ pub trait Tracker {
     /// [Tracker::track_pauli] for multiple Paulis but all within the same frame.
     fn track_pauli_string(&mut self, string: PauliString<Self::Pauli>);
 
+    /// Track a new frame consisting of the Pauli given by its [tableau
+    /// encoding](crate::pauli::tableau_encoding) `byte` at qu`bit`, cf.
+    /// [track_pauli](Self::track_pauli). This is useful when the Paulis come from data
+    /// or over an FFI boundary.
+    ///
+    /// # Errors
+    /// Errors with [InvalidU8] if `byte` is not a valid tableau encoding, i.e., if it is
+    /// larger than 3. Nothing is tracked in this case.
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() { #![cfg_attr(coverage_nightly, coverage(off))]
+    /// # use pauli_tracker::{collection::{BufferedVector, Init}, pauli::{InvalidU8,
+    /// #     Pauli, PauliTuple}, tracker::{Tracker, live::Live}};
+    /// let mut tracker = Live::<BufferedVector<PauliTuple>>::init(2);
+    /// tracker.track_pauli_byte(1, 3).unwrap();
+    /// assert_eq!(tracker[1], PauliTuple::Y);
+    /// assert_eq!(tracker.track_pauli_byte(0, 4), Err(InvalidU8(4)));
+    /// # }
+    /// ```
+    fn track_pauli_byte(&mut self, bit: usize, byte: u8) -> Result<(), InvalidU8> {
+        let pauli = PauliDense::try_from(byte)?;
+        self.track_pauli(bit, Self::Pauli::new_product(pauli.get_z(), pauli.get_x()));
+        Ok(())
+    }
+
     track_pauli!((track_x, X), (track_y, Y), (track_z, Z),);
 
     clifford_helper::trait_gates!();
 
+    try_gates!();
+
+    /// Apply the Control Z gate on each pair of qubits in `edges`, e.g., on the edges of
+    /// a graph to prepare a graph state.
+    ///
+    /// The default implementation calls [cz](Self::cz) for each edge.
+    fn cz_layer(&mut self, edges: &[(usize, usize)]) {
+        for &(bit_a, bit_b) in edges {
+            self.cz(bit_a, bit_b);
+        }
+    }
+
+    /// Apply the Control X gate on each `(control, target)` pair in `pairs`.
+    ///
+    /// The default implementation calls [cx](Self::cx) for each pair.
+    fn cx_layer(&mut self, pairs: &[(usize, usize)]) {
+        for &(control, target) in pairs {
+            self.cx(control, target);
+        }
+    }
+
     movements!(
         (move_x_to_x, "X", "X"),
         (move_x_to_z, "X", "Z"),
@@ -196,6 +392,26 @@ pub trait Tracker {
 
     /// Remove the Pauli stack on qu`bit`, if it is present.
     fn measure(&mut self, bit: usize) -> Result<Self::Stack, MissingBit>;
+
+    /// Prepare the qu`bit` in the |0> state, i.e., (re)initialize its stack as in
+    /// [new_qubit](Self::new_qubit), dropping any previously tracked Paulis on it.
+    ///
+    /// Since Z|0> = |0>, Z corrections that are tracked on the qu`bit` directly
+    /// afterwards, i.e., before any gate acts on it, are trivial (and Y corrections act
+    /// like X corrections).
+    fn prepare_zero(&mut self, bit: usize) {
+        self.new_qubit(bit);
+    }
+
+    /// Prepare the qu`bit` in the |+> state, i.e., (re)initialize its stack as in
+    /// [new_qubit](Self::new_qubit), dropping any previously tracked Paulis on it.
+    ///
+    /// Since X|+> = |+>, X corrections that are tracked on the qu`bit` directly
+    /// afterwards, i.e., before any gate acts on it, are trivial (and Y corrections act
+    /// like Z corrections).
+    fn prepare_plus(&mut self, bit: usize) {
+        self.new_qubit(bit);
+    }
 }
 
 // {{ some helpers for simpler gate implementations
@@ -233,11 +449,16 @@ macro_rules! unwrap_get_two_mut {
         })
     };
 }
+
 // }}
 
 pub mod frames;
+pub mod half_frames;
 pub mod live;
 
+mod tee;
+pub use tee::TeeTracker;
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -262,7 +483,7 @@ mod tests {
         // the encoding is according to crate::pauli::tableau_encoding, i.e., 0=I, 2=X,
         // 3=Y, 1=Z
 
-        pub const N_SINGLES: usize = 20;
+        pub const N_SINGLES: usize = 22;
         #[rustfmt::skip]
         const SINGLE_GENERATORS: [(&str, [u8; 2]); N_SINGLES] =
             // (name, result: [conjugate Z, conjugate X])
@@ -285,6 +506,8 @@ mod tests {
                 ("SX",   [3, 2]),
                 ("SXDG", [3, 2]),
                 ("H_yz", [3, 2]),
+                ("V",    [3, 2]),
+                ("VDG",  [3, 2]),
                 // these here are not conjugations with unitary operators, however it
                 // still works, because the operation is a homomorphism
                 ("remove_z", [0, 2]),
@@ -312,6 +535,8 @@ mod tests {
                     <$tracker>::sx,
                     <$tracker>::sxdg,
                     <$tracker>::hyz,
+                    <$tracker>::v,
+                    <$tracker>::vdg,
                     <$tracker>::remove_z,
                     <$tracker>::remove_x,
                 ]
@@ -490,6 +715,9 @@ mod tests {
             fn new_qubit(&mut self, bit: usize) -> Option<Self::Stack> {
                 self.paulis.insert(bit, PauliDense::I)
             }
+            fn contains_qubit(&self, bit: usize) -> bool {
+                self.paulis.contains_key(&bit)
+            }
             fn track_pauli(&mut self, _: usize, _: Self::Pauli) {
                 todo!()
             }
@@ -18,16 +18,19 @@ during the actual execution of a circuit, the [Live] tracker is more useful.
 [Live]: super::live::Live
 */
 
-use std::mem;
+use std::{fmt, mem};
 
+use hashbrown::{HashMap, HashSet};
+#[cfg(feature = "rayon")]
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
-use super::{MissingBit, PauliString, Tracker};
+use super::{MissingBit, PauliString, Tracker, live::Live};
 use crate::{
     boolean_vector::BooleanVector,
-    collection::{Base, Full, Init, IterableBase},
+    collection::{Base, BufferedVector, Full, Init, Iterable, IterableBase},
     pauli::{Pauli, PauliStack, PauliTuple},
 };
 
@@ -41,14 +44,40 @@ pub mod induced_order;
 /// enforced by the [collection] traits). The [collection] module provides some
 /// compatible storage types.
 ///
+/// # Serialization
+/// With the `serde` feature, the (de)serialization contains a small header with the
+/// version of the representation, [SERDE_VERSION]. Deserializing data with another
+/// version errors, instead of silently misinterpreting the data. For backwards
+/// compatibility, human-readable formats can still be deserialized from the
+/// representation without a header (binary formats require the header).
+///
+/// # Debug
+/// If the [Debug] representation of the storage is longer than 10000 characters, the
+/// [Debug] implementation elides it, e.g., `Frames { storage: .., frames_num: 20 }`. Use
+/// the alternate flag, `{:#?}`, to print all the Paulis anyway.
+///
 /// [collection]: crate::collection
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Frames<Storage> {
     storage: Storage,
     frames_num: usize,
 }
 
+impl<S: fmt::Debug> fmt::Debug for Frames<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let alternate = f.alternate();
+        let mut debug = f.debug_struct("Frames");
+        super::debug_storage(&mut debug, &self.storage, alternate);
+        debug.field("frames_num", &self.frames_num).finish()
+    }
+}
+
+/// The version of the serde representation of [Frames]; it is bumped whenever the
+/// representation changes incompatibly.
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub const SERDE_VERSION: u32 = 1;
+
 /// The Error when one overwrites a qubit's Pauli stack.
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Error)]
 #[error("the Pauli stack for bit {bit} has been overwritten")]
@@ -84,6 +113,90 @@ impl<T: Default> Default for MoveError<T> {
     }
 }
 
+/// The Error when one tries to push a frame, given as string, that doesn't fit to the
+/// tracked qubits, as in [push_frame_str](Frames::push_frame_str).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Error)]
+pub enum FrameShapeError {
+    /// The length of the string is not the number of qubits.
+    #[error("the frame has length {found}, but there are {expected} qubits")]
+    Length {
+        /// The number of qubits.
+        expected: usize,
+        /// The length of the frame.
+        found: usize,
+    },
+    /// The character at `position` is not one of 'I', 'X', 'Y' or 'Z'.
+    #[error("'{chr}' at position {position} is not a Pauli")]
+    Pauli {
+        /// The position in the frame.
+        position: usize,
+        /// The invalid character.
+        chr: char,
+    },
+    /// See [MissingBit].
+    #[error(transparent)]
+    MissingBit(#[from] MissingBit),
+}
+
+#[doc = non_semantic_default!()]
+impl Default for FrameShapeError {
+    fn default() -> Self {
+        Self::MissingBit(MissingBit::default())
+    }
+}
+
+/// The Error when one tries to get the dependencies of a qubit, as in
+/// [dependencies_of](Frames::dependencies_of).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Error)]
+pub enum DependencyError {
+    /// The qubit has a non-identity Pauli in `frame`, but the map has only `len`
+    /// entries, i.e., `frame >= len`.
+    #[error(
+        "the map has {len} entries, but there's a non-identity Pauli in frame {frame}"
+    )]
+    ShortMap {
+        /// The frame without an entry in the map.
+        frame: usize,
+        /// The length of the map.
+        len: usize,
+    },
+    /// See [MissingBit].
+    #[error(transparent)]
+    MissingBit(#[from] MissingBit),
+}
+
+#[doc = non_semantic_default!()]
+impl Default for DependencyError {
+    fn default() -> Self {
+        Self::MissingBit(MissingBit::default())
+    }
+}
+
+/// The Error when one tries to build [Frames] from stacks that don't have the same
+/// length, as in [from_stacks](Frames::from_stacks).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Error)]
+#[error(
+    "the Pauli stack for bit {bit} has length {found} (first mismatching part, z before \
+     x), but expected {expected}"
+)]
+pub struct InconsistentFrames {
+    /// The bit.
+    pub bit: usize,
+    /// The expected length, i.e., the number of frames.
+    pub expected: usize,
+    /// The length of the first mismatching part of the stack, checking the `z` part
+    /// before the `x` part.
+    pub found: usize,
+}
+
+/// A checkpoint of a [Frames] tracker, created with [checkpoint](Frames::checkpoint),
+/// to which the tracker can be [restore](Frames::restore)d.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FramesCheckpoint {
+    frames_num: usize,
+    qubits_num: usize,
+}
+
 impl<S> Frames<S> {
     /// Create a new [Frames] instance with a given storage and number of frames.
     ///
@@ -113,6 +226,58 @@ impl<S> Frames<S> {
     }
 }
 
+impl<B: BooleanVector> Frames<BufferedVector<PauliStack<B>>> {
+    /// Create a new [Frames] instance from the `stacks`, where the i-th stack is the
+    /// Pauli stack of qubit i. The number of frames is inferred from the first stack.
+    ///
+    /// Errors if not all stacks have the same length, including the `z` and `x` parts
+    /// of each stack. Compare [new_unchecked](Self::new_unchecked).
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() { #![cfg_attr(coverage_nightly, coverage(off))]
+    /// # use pauli_tracker::{pauli::PauliStack, tracker::frames::{Frames,
+    /// #     InconsistentFrames}};
+    /// let frames = Frames::from_stacks(vec![
+    ///     PauliStack::<Vec<bool>>::try_from_str("10", "01").unwrap(),
+    ///     PauliStack::<Vec<bool>>::try_from_str("00", "11").unwrap(),
+    /// ])
+    /// .unwrap();
+    /// assert_eq!(frames.frames_num(), 2);
+    /// assert_eq!(
+    ///     Frames::from_stacks(vec![
+    ///         PauliStack::<Vec<bool>>::try_from_str("10", "01").unwrap(),
+    ///         PauliStack::<Vec<bool>>::try_from_str("00", "1").unwrap(),
+    ///     ]),
+    ///     Err(InconsistentFrames { bit: 1, expected: 2, found: 1 })
+    /// );
+    /// # }
+    /// ```
+    pub fn from_stacks(
+        stacks: impl IntoIterator<Item = PauliStack<B>>,
+    ) -> Result<Self, InconsistentFrames> {
+        let stacks = stacks.into_iter().collect::<Vec<_>>();
+        let frames_num = stacks.first().map(|stack| stack.z.len()).unwrap_or(0);
+        for (bit, stack) in stacks.iter().enumerate() {
+            if stack.z.len() != frames_num || stack.x.len() != frames_num {
+                return Err(InconsistentFrames {
+                    bit,
+                    expected: frames_num,
+                    found: if stack.z.len() != frames_num {
+                        stack.z.len()
+                    } else {
+                        stack.x.len()
+                    },
+                });
+            }
+        }
+        Ok(Self {
+            storage: BufferedVector::from(stacks),
+            frames_num,
+        })
+    }
+}
+
 impl<S: Init> Init for Frames<S> {
     fn init(len: usize) -> Self {
         Self {
@@ -196,20 +361,29 @@ where
     type Stack = PauliStack<B>;
     type Pauli = PauliTuple;
 
+    const NATIVE_GATES: &'static [&'static str] = &[
+        "s", "h", "sh", "hs", "shs", "cz", "cx", "cy", "swap", "zcx", "zcy", "iswap",
+    ];
+
     fn new_qubit(&mut self, qubit: usize) -> Option<Self::Stack> {
         self.storage.insert(qubit, Self::Stack::zeros(self.frames_num))
     }
 
+    fn contains_qubit(&self, qubit: usize) -> bool {
+        self.storage.get(qubit).is_some()
+    }
+
     fn track_pauli(&mut self, qubit: usize, pauli: Self::Pauli) {
         if self.storage.is_empty() {
             return;
         }
-        for (i, p) in self.storage.iter_pairs_mut() {
-            if i == qubit {
-                p.push(pauli);
-            } else {
-                p.push(Self::Pauli::new_i());
-            }
+        // pushing the identity everywhere first and then fixing the one Pauli allows a
+        // fast path for contiguous storages, cf. Iterable::for_each_value_mut
+        self.storage.for_each_value_mut(|p| p.push(Self::Pauli::new_i()));
+        if let Some(p) = self.storage.get_mut(qubit) {
+            let idx = p.z.len() - 1;
+            p.z.set(idx, pauli.get_z());
+            p.x.set(idx, pauli.get_x());
         }
         self.frames_num += 1;
     }
@@ -218,9 +392,7 @@ where
         if self.storage.is_empty() {
             return;
         }
-        for (_, p) in self.storage.iter_pairs_mut() {
-            p.push(Self::Pauli::new_i());
-        }
+        self.storage.for_each_value_mut(|p| p.push(Self::Pauli::new_i()));
         for (i, p) in string {
             match self.storage.get_mut(i) {
                 Some(pauli) => {
@@ -293,6 +465,57 @@ where
         // b.x in a temporary variable, but it's not clear whether that would be faster
     }
 
+    fn cz_layer(&mut self, edges: &[(usize, usize)]) {
+        // CZ gates commute and don't change the x parts, so we can update all z parts
+        // with the original x parts, touching each stack only once
+        let mut neighbours = HashMap::<usize, Vec<usize>>::new();
+        for &(bit_a, bit_b) in edges {
+            assert_ne!(bit_a, bit_b, "cz_layer: the qubits of an edge are the same");
+            neighbours.entry(bit_a).or_default().push(bit_b);
+            neighbours.entry(bit_b).or_default().push(bit_a);
+        }
+        let x = clone_parts(&self.storage, neighbours.keys().copied(), |s| &s.x, "cz");
+        for (bit, neighbours) in neighbours {
+            let z = &mut unwrap_get_mut!(self.storage, bit, "cz").z;
+            for neighbour in neighbours {
+                z.xor_inplace(&x[&neighbour]);
+            }
+        }
+    }
+
+    fn cx_layer(&mut self, pairs: &[(usize, usize)]) {
+        // CX gates commute if no control is also a target, since then the x parts of the
+        // controls and the z parts of the targets don't change, i.e., we can update the
+        // stacks with the original values, touching each stack at most twice; otherwise
+        // we have to apply the gates in order
+        let mut controls = HashMap::<usize, Vec<usize>>::new();
+        let mut targets = HashMap::<usize, Vec<usize>>::new();
+        for &(control, target) in pairs {
+            controls.entry(target).or_default().push(control);
+            targets.entry(control).or_default().push(target);
+        }
+        if controls.keys().any(|target| targets.contains_key(target)) {
+            for &(control, target) in pairs {
+                self.cx(control, target);
+            }
+            return;
+        }
+        let x = clone_parts(&self.storage, targets.keys().copied(), |s| &s.x, "cx");
+        let z = clone_parts(&self.storage, controls.keys().copied(), |s| &s.z, "cx");
+        for (target, controls) in controls {
+            let target = &mut unwrap_get_mut!(self.storage, target, "cx").x;
+            for control in controls {
+                target.xor_inplace(&x[&control]);
+            }
+        }
+        for (control, targets) in targets {
+            let control = &mut unwrap_get_mut!(self.storage, control, "cx").z;
+            for target in targets {
+                control.xor_inplace(&z[&target]);
+            }
+        }
+    }
+
     movements!(
         (move_z_to_z, z, z, "Z", "Z"),
         (move_z_to_x, z, x, "Z", "X"),
@@ -307,11 +530,107 @@ where
     }
 }
 
+// clone the `part`s of the stacks of the `bits`, panicking if a qubit is missing
+fn clone_parts<S, B>(
+    storage: &S,
+    bits: impl IntoIterator<Item = usize>,
+    part: fn(&PauliStack<B>) -> &B,
+    gate: &str,
+) -> HashMap<usize, B>
+where
+    S: Base<TB = PauliStack<B>>,
+    B: BooleanVector,
+{
+    bits.into_iter()
+        .map(|bit| match storage.get(bit) {
+            Some(stack) => (bit, part(stack).clone()),
+            None => panic!("{gate}: qubit {bit} does not exist"),
+        })
+        .collect()
+}
+
+// the frames where the Pauli of `stack` is not the identity, computed block-wise
+fn support<B: BooleanVector>(stack: &PauliStack<B>) -> B {
+    let mut support = stack.z.clone();
+    support.or_inplace(&stack.x);
+    support
+}
+
 impl<S, B> Frames<S>
 where
     S: IterableBase<T = PauliStack<B>>,
     B: BooleanVector,
 {
+    /// Create a new [Frames] instance with `num_qubits` qubits and the given `frames`,
+    /// where the i-th [PauliString] is the i-th frame; this is the inverse of
+    /// [transpose](Self::transpose) (up to the sparsity of the [PauliString]s).
+    ///
+    /// As in [track_pauli_string](Tracker::track_pauli_string), Paulis on qubits that are
+    /// not in `0..num_qubits` are ignored.
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() { #![cfg_attr(coverage_nightly, coverage(off))]
+    /// # use pauli_tracker::{collection::BufferedVector, pauli::{Pauli, PauliStack,
+    /// #     PauliTuple}, tracker::frames::Frames};
+    /// let frames =
+    ///     Frames::<BufferedVector<PauliStack<Vec<bool>>>>::from_pauli_strings(2, &[
+    ///         vec![(0, PauliTuple::X)],
+    ///         vec![(0, PauliTuple::Z), (1, PauliTuple::Y)],
+    ///     ]);
+    /// assert_eq!(frames.frames_num(), 2);
+    /// assert_eq!(frames.transpose::<PauliTuple>(2), vec![
+    ///     vec![PauliTuple::X, PauliTuple::I],
+    ///     vec![PauliTuple::Z, PauliTuple::Y],
+    /// ]);
+    /// # }
+    /// ```
+    pub fn from_pauli_strings(
+        num_qubits: usize,
+        frames: &[PauliString<PauliTuple>],
+    ) -> Self
+    where
+        S: Init,
+    {
+        let mut storage = S::init(num_qubits);
+        for stack in storage.values_mut() {
+            *stack = PauliStack::zeros(frames.len());
+        }
+        for (idx, frame) in frames.iter().enumerate() {
+            for (bit, pauli) in frame {
+                if let Some(stack) = storage.get_mut(*bit) {
+                    stack.z.set(idx, pauli.get_z());
+                    stack.x.set(idx, pauli.get_x());
+                }
+            }
+        }
+        Self {
+            storage,
+            frames_num: frames.len(),
+        }
+    }
+
+    /// Reserve capacity for at least `additional` more frames in the stacks of all
+    /// current qubits, cf. [BooleanVector::reserve]. Stacks of qubits that are added
+    /// later are not affected.
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() { #![cfg_attr(coverage_nightly, coverage(off))]
+    /// # use pauli_tracker::{collection::{Init, Map}, pauli::PauliStack,
+    /// #     tracker::frames::Frames};
+    /// let mut frames = Frames::<Map<PauliStack<Vec<bool>>>>::init(2);
+    /// frames.reserve_frames(10);
+    /// assert!(frames.get(1).unwrap().z.capacity() >= 10);
+    /// # }
+    /// ```
+    pub fn reserve_frames(&mut self, additional: usize) {
+        for (_, stack) in self.storage.iter_pairs_mut() {
+            stack.z.reserve(additional);
+            stack.x.reserve(additional);
+        }
+    }
+
     /// Pop the last tracked Pauli frame.
     ///
     /// If you do this to get all frames, you might want to use
@@ -347,141 +666,1377 @@ where
         Some(ret)
     }
 
-    /// Measure a qu`bit` and store the according stack of tracked Paulis into
-    /// `storage`. Errors when the qu`bit` is not present in the tracker.
-    pub fn measure_and_store(
-        &mut self,
-        bit: usize,
-        storage: &mut impl Base<TB = PauliStack<B>>,
-    ) -> Result<(), MoveError<B>> {
-        match storage.insert(bit, self.measure(bit)?) {
-            Some(p) => Err(OverwriteStack { bit, stack: p }.into()),
-            None => Ok(()),
+    /// Create a lightweight checkpoint of the current number of frames, cf.
+    /// [restore](Self::restore).
+    pub fn checkpoint(&self) -> FramesCheckpoint {
+        FramesCheckpoint {
+            frames_num: self.frames_num,
+            qubits_num: self.storage.len(),
         }
     }
 
-    /// Transpose the frames with sorted qubits. The result is a non-sparse matrix of
-    /// Paulis.
-    ///
-    /// Depending on the use case, [stacked_transpose](Self::stacked_transpose) can be
-    /// more efficient.
+    /// Remove all frames that were tracked after the `checkpoint` was created, by
+    /// truncating all stacks.
     ///
-    /// # Panics
-    /// Panics if `num_qubits` is smaller the highest qubit index that has been tracked.
+    /// This is only valid if no qubits were added or removed since the `checkpoint` was
+    /// created (which is debug-asserted; only the number of qubits is checked) and if no
+    /// frames were popped. Note that gates that were applied after the `checkpoint` was
+    /// created are **not** undone on the frames that already existed at the checkpoint,
+    /// i.e., the tracker is only fully restored if, since the checkpoint, the gates
+    /// acted trivially on the old frames.
     ///
     /// # Examples
     /// ```
     /// # fn main() { #![cfg_attr(coverage_nightly, coverage(off))]
-    /// # use pauli_tracker::{collection::NaiveVector, pauli::{self, PauliTuple},
-    /// #     tracker::frames::Frames};
-    /// type PauliStack = pauli::PauliStack<Vec<bool>>;
-    ///
-    /// let frames = Frames::<NaiveVector<_>>::new_unchecked(
-    ///     vec![
-    ///         //               frame  Z 12  X 12             qubit
-    ///         PauliStack::try_from_str("10", "01").unwrap(), // 0
-    ///         PauliStack::try_from_str("11", "10").unwrap(), // 1
-    ///         PauliStack::try_from_str("11", "01").unwrap(), // 2
-    ///     ]
-    ///     .into(),
-    ///     2,
-    /// );
+    /// # use pauli_tracker::{collection::{BufferedVector, Init}, pauli::PauliStack,
+    /// #     tracker::{Tracker, frames::Frames}};
+    /// let mut frames = Frames::<BufferedVector<PauliStack<Vec<bool>>>>::init(2);
+    /// frames.track_x(0);
+    /// frames.cx(0, 1);
+    /// let checkpoint = frames.checkpoint();
+    /// let check = frames.clone();
+    /// frames.track_z(1);
+    /// frames.track_y(0);
+    /// frames.restore(checkpoint);
+    /// assert_eq!(frames, check);
+    /// # }
+    /// ```
+    pub fn restore(&mut self, checkpoint: FramesCheckpoint) {
+        debug_assert_eq!(
+            self.storage.len(),
+            checkpoint.qubits_num,
+            "qubits have been added or removed since the checkpoint"
+        );
+        debug_assert!(
+            self.frames_num >= checkpoint.frames_num,
+            "frames have been popped since the checkpoint"
+        );
+        for stack in self.storage.values_mut() {
+            stack.z.resize(checkpoint.frames_num, false);
+            stack.x.resize(checkpoint.frames_num, false);
+        }
+        self.frames_num = checkpoint.frames_num;
+    }
+
+    /// Pad all stacks with identity frames, so that there are `target` frames, e.g., to
+    /// align the frames of two trackers. Does nothing if `target` is not bigger than
+    /// [frames_num](Self::frames_num).
     ///
-    /// let transposed = vec![
-    ///     // bit  0       1       2        frame
-    ///     vec![(1, 0), (1, 1), (1, 0)], // 1
-    ///     vec![(0, 1), (1, 0), (1, 1)], // 2
-    /// ]
-    /// .into_iter()
-    /// .map(|frame| frame.into_iter().map(|(z, x)| (z == 1, x == 1)))
-    /// .map(|frame| frame.map(PauliTuple::from).collect::<Vec<PauliTuple>>())
-    /// .collect::<Vec<_>>();
+    /// # Examples
+    /// ```
+    /// # fn main() { #![cfg_attr(coverage_nightly, coverage(off))]
+    /// # use pauli_tracker::{collection::{BufferedVector, Init}, pauli::PauliStack,
+    /// #     tracker::{Tracker, frames::Frames}};
+    /// let mut frames = Frames::<BufferedVector<PauliStack<Vec<bool>>>>::init(2);
+    /// frames.track_x(0);
+    /// frames.grow_frames_to(3);
+    /// assert_eq!(frames.frames_num(), 3);
+    /// assert_eq!(frames.get(0), Some(&PauliStack::try_from_str("000", "100").unwrap()));
+    /// # }
+    /// ```
+    pub fn grow_frames_to(&mut self, target: usize) {
+        if target <= self.frames_num {
+            return;
+        }
+        for stack in self.storage.values_mut() {
+            stack.z.resize(target, false);
+            stack.x.resize(target, false);
+        }
+        self.frames_num = target;
+    }
+
+    /// Apply a single-qubit gate, given as its action on a [PauliStack], to all qubits at
+    /// once, e.g., `frames.apply_to_all(PauliStack::h)` for a global basis change.
     ///
-    /// assert_eq!(frames.transpose::<PauliTuple>(3), transposed);
+    /// # Examples
+    /// ```
+    /// # fn main() { #![cfg_attr(coverage_nightly, coverage(off))]
+    /// # use pauli_tracker::{collection::{BufferedVector, Init}, pauli::PauliStack,
+    /// #     tracker::{Tracker, frames::Frames}};
+    /// let mut frames = Frames::<BufferedVector<PauliStack<Vec<bool>>>>::init(2);
+    /// frames.track_x(0);
+    /// frames.track_z(1);
+    /// frames.apply_to_all(PauliStack::h);
+    /// assert_eq!(frames.get(0), Some(&PauliStack::try_from_str("10", "00").unwrap()));
+    /// assert_eq!(frames.get(1), Some(&PauliStack::try_from_str("00", "01").unwrap()));
     /// # }
     /// ```
-    // for efficiency, one should flatten the matrix internally, but the matrix is not
-    // really used in matrix operations (usually, I think), so to have a return type with
-    // a more flexibel API, we don't do that
-    pub fn transpose<P: Pauli + Clone>(&self, num_qubits: usize) -> Vec<Vec<P>> {
-        let mut ret = Vec::with_capacity(self.frames_num);
-        for i in 0..self.frames_num {
-            let frame = self
-                .get_frame::<P>(i)
-                .expect("frames_num bigger than actual number of frames");
-            let mut paulis = vec![P::I; num_qubits];
-            for (i, p) in frame {
-                paulis[i] = p;
+    pub fn apply_to_all(&mut self, gate: impl FnMut(&mut PauliStack<B>)) {
+        self.storage.values_mut().for_each(gate)
+    }
+
+    /// Iterate lazily over all frames, in the order in which they were tracked, as
+    /// [PauliString]s, compare [get_frame](Self::get_frame).
+    ///
+    /// If you need all frames at once in a dense representation, you might want to use
+    /// [transpose](Self::transpose).
+    pub fn iter_frames<P: Pauli>(
+        &self,
+    ) -> impl ExactSizeIterator<Item = PauliString<P>> + '_ {
+        // the frame is None only if there are no qubits anymore
+        (0..self.frames_num).map(|idx| self.get_frame(idx).unwrap_or_default())
+    }
+
+    /// Call `f` with the index and the [PauliString] of each frame, in the order in
+    /// which they were tracked.
+    ///
+    /// In contrast to [iter_frames](Self::iter_frames), the same buffer is reused for
+    /// all frames, so the memory overhead is only the size of one frame, e.g., for
+    /// streaming the frames of a huge tracker into a file.
+    pub fn for_each_frame<F: FnMut(usize, &PauliString<PauliTuple>)>(&self, mut f: F) {
+        let mut frame = Vec::with_capacity(self.storage.len());
+        for idx in 0..self.frames_num {
+            frame.clear();
+            for (bit, stack) in self.storage.iter_pairs() {
+                if let Some(pauli) = stack.get(idx) {
+                    frame.push((bit, pauli));
+                }
             }
-            ret.push(paulis);
+            f(idx, &frame);
         }
-        ret
     }
 
-    /// Similar to [transpose](Self::transpose), but use [PauliStack] for the frames.
+    /// Push a complete frame, given as a string of 'I', 'X', 'Y' and 'Z' characters,
+    /// where the character at position `i` is the Pauli for qubit `i`.
     ///
-    /// # Panics
-    /// Panics if `num_qubits` is smaller the highest qubit index that has been tracked.
+    /// Errors if the length of the string is not the number of qubits, if the string
+    /// contains any other characters, or if the qubits are not exactly `0..s.len()`. In
+    /// this case the tracker is not changed.
     ///
     /// # Examples
     /// ```
     /// # fn main() { #![cfg_attr(coverage_nightly, coverage(off))]
-    /// # use pauli_tracker::{collection::NaiveVector, pauli::{self, PauliTuple},
-    /// #     tracker::frames::Frames};
-    /// type PauliStack = pauli::PauliStack<Vec<bool>>;
+    /// # use pauli_tracker::{collection::{BufferedVector, Init}, pauli::{PauliStack,
+    /// #     Pauli, PauliTuple}, tracker::frames::Frames};
+    /// let mut frames = Frames::<BufferedVector<PauliStack<Vec<bool>>>>::init(3);
+    /// frames.push_frame_str("XIY").unwrap();
     /// assert_eq!(
-    ///     Frames::<NaiveVector<_>>::new_unchecked(vec![
-    ///         PauliStack::try_from_str("10", "01").unwrap(),
-    ///         PauliStack::try_from_str("11", "10").unwrap(),
-    ///         PauliStack::try_from_str("11", "01").unwrap(),
-    ///     ].into(), 2).stacked_transpose(3),
-    ///     vec![
-    ///         PauliStack::try_from_str("111", "010").unwrap(),
-    ///         PauliStack::try_from_str("011", "101").unwrap(),
-    ///     ]
+    ///     frames.get_frame(0),
+    ///     Some(vec![(0, PauliTuple::X), (1, PauliTuple::I), (2, PauliTuple::Y)])
     /// );
     /// # }
-    pub fn stacked_transpose(&self, num_qubits: usize) -> Vec<PauliStack<B>> {
-        let mut ret = Vec::with_capacity(self.frames_num);
-        for i in 0..self.frames_num {
-            let frame = self
-                .get_frame::<PauliTuple>(i)
-                .expect("frames_num bigger than actual number of frames");
-            let mut stack = PauliStack::<B>::zeros(num_qubits);
-            for (i, p) in frame {
-                stack.z.set(i, p.0);
-                stack.x.set(i, p.1);
+    /// ```
+    pub fn push_frame_str(&mut self, s: &str) -> Result<(), FrameShapeError> {
+        let frame = s
+            .chars()
+            .enumerate()
+            .map(|(position, chr)| match chr {
+                'I' => Ok(PauliTuple::new_i()),
+                'X' => Ok(PauliTuple::new_x()),
+                'Y' => Ok(PauliTuple::new_y()),
+                'Z' => Ok(PauliTuple::new_z()),
+                _ => Err(FrameShapeError::Pauli { position, chr }),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if frame.len() != self.storage.len() {
+            return Err(FrameShapeError::Length {
+                expected: self.storage.len(),
+                found: frame.len(),
+            });
+        }
+        if let Some(bit) = (0..frame.len()).find(|&bit| self.storage.get(bit).is_none()) {
+            return Err(MissingBit(bit).into());
+        }
+        for (bit, pauli) in frame.into_iter().enumerate() {
+            match self.storage.get_mut(bit) {
+                Some(stack) => stack.push(pauli),
+                // checked above
+                None => unreachable!(),
             }
-            ret.push(stack);
         }
-        ret
+        self.frames_num += 1;
+        Ok(())
     }
-}
 
-impl<S, B> Frames<S>
-where
-    S: Full<T = PauliStack<B>> + Default,
-    B: BooleanVector,
-{
-    /// Measure all qubits and put the according stack of Paulis into `storage`, i.e.,
-    /// do [Frames::measure_and_store] for all qubits.
-    pub fn measure_and_store_all(&mut self, storage: &mut impl Base<TB = PauliStack<B>>) {
-        for (bit, pauli) in
-            // mem::replace(&mut self.storage, S::init(0, PauliStack::default()))
-            mem::take(&mut self.storage).into_iter()
-        {
-            storage.insert(bit, pauli);
+    /// Remove all frames for which the predicate `f` returns false, keeping the order of
+    /// the remaining frames. The frames are passed to `f` as in
+    /// [for_each_frame](Self::for_each_frame).
+    ///
+    /// Note that the indices of the remaining frames shift, so any mapping of the frames
+    /// to their origins, e.g., for [induced_order], has to be filtered accordingly.
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() { #![cfg_attr(coverage_nightly, coverage(off))]
+    /// # use pauli_tracker::{collection::{BufferedVector, Init}, pauli::{Pauli,
+    /// #     PauliStack, PauliTuple}, tracker::{Tracker, frames::Frames}};
+    /// let mut frames = Frames::<BufferedVector<PauliStack<Vec<bool>>>>::init(2);
+    /// frames.track_x(0);
+    /// frames.track_z(1);
+    /// frames.track_y(0);
+    /// frames.filter_frames(|frame| frame[0].1 != PauliTuple::I);
+    /// assert_eq!(frames.frames_num(), 2);
+    /// assert_eq!(frames.get(0), Some(&PauliStack::try_from_str("01", "11").unwrap()));
+    /// # }
+    /// ```
+    pub fn filter_frames<F: Fn(&PauliString<PauliTuple>) -> bool>(&mut self, f: F) {
+        fn retain<B: BooleanVector>(vec: &B, keep: &[bool]) -> B {
+            vec.iter_vals()
+                .zip(keep)
+                .filter_map(|(flag, keep)| keep.then_some(flag))
+                .collect()
+        }
+        let mut keep = Vec::with_capacity(self.frames_num);
+        self.for_each_frame(|_, frame| keep.push(f(frame)));
+        for stack in self.storage.values_mut() {
+            stack.z = retain(&stack.z, &keep);
+            stack.x = retain(&stack.x, &keep);
         }
+        self.frames_num = keep.into_iter().filter(|keep| *keep).count();
     }
-}
-
-#[cfg(test)]
-mod tests {
-    use coverage_helper::test;
 
-    use super::*;
+    /// Get the weight of each frame, i.e., the number of qubits with a non-identity
+    /// Pauli in the frame.
+    ///
+    /// This works block-wise on the stacks via [BooleanVector::or_inplace] and
+    /// [BooleanVector::for_each_one], so it is fast for the bit-packed storages.
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() { #![cfg_attr(coverage_nightly, coverage(off))]
+    /// # use pauli_tracker::{collection::{BufferedVector, Init}, pauli::PauliStack,
+    /// #     tracker::{Tracker, frames::Frames}};
+    /// let mut frames = Frames::<BufferedVector<PauliStack<Vec<bool>>>>::init(3);
+    /// frames.track_x(0);
+    /// frames.track_z(1);
+    /// frames.cx(0, 1);
+    /// frames.cx(0, 2);
+    /// assert_eq!(frames.weight_per_frame(), vec![3, 2]);
+    /// # }
+    /// ```
+    pub fn weight_per_frame(&self) -> Vec<usize> {
+        let mut weights = vec![0; self.frames_num];
+        for stack in self.storage.values() {
+            support(stack).for_each_one(|frame| weights[frame] += 1);
+        }
+        weights
+    }
 
-    // we only check the basic functionality here, more complicated circuits are tested
+    /// Get the total weight of all frames, i.e., the number of non-identity Paulis
+    /// summed over all frames and qubits; the sum of [weight_per_frame].
+    ///
+    /// This counts directly on the stacks, with a popcount via
+    /// [BooleanVector::count_ones], so it is cheaper than [transpose](Self::transpose)
+    /// and counting afterwards.
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() { #![cfg_attr(coverage_nightly, coverage(off))]
+    /// # use pauli_tracker::{collection::{BufferedVector, Init}, pauli::PauliStack,
+    /// #     tracker::{Tracker, frames::Frames}};
+    /// let mut frames = Frames::<BufferedVector<PauliStack<Vec<bool>>>>::init(3);
+    /// frames.track_x(0);
+    /// frames.track_z(1);
+    /// frames.cx(0, 1);
+    /// frames.cx(0, 2);
+    /// assert_eq!(frames.total_weight(), 5);
+    /// # }
+    /// ```
+    ///
+    /// [weight_per_frame]: Self::weight_per_frame
+    pub fn total_weight(&self) -> usize {
+        self.storage.values().map(|stack| support(stack).count_ones()).sum()
+    }
+
+    /// Get the qubits that the correction on `qubit` depends on, i.e., `map`\[i\] for
+    /// all frames i where the Pauli on `qubit` is not the identity; sorted and without
+    /// duplicates.
+    ///
+    /// This is the dependency set of a single node of the graph of
+    /// [induced_order::get_order] (compare it for the meaning of `map`), without
+    /// building the whole graph. However, in contrast to the graph, redundant
+    /// dependencies are not removed.
+    ///
+    /// # Errors
+    /// Errors with [DependencyError::MissingBit] if `qubit` is not in the tracker, and
+    /// with [DependencyError::ShortMap] if there's a frame i with a non-identity Pauli
+    /// on `qubit` for which `map` has no entry, i.e., i >= `map.len()`.
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() { #![cfg_attr(coverage_nightly, coverage(off))]
+    /// # use pauli_tracker::{collection::{BufferedVector, Init}, pauli::PauliStack,
+    /// #     tracker::{MissingBit, Tracker, frames::{DependencyError, Frames}}};
+    /// let mut frames = Frames::<BufferedVector<PauliStack<Vec<bool>>>>::init(3);
+    /// frames.track_x(1); // induced by qubit 0
+    /// frames.track_z(2); // induced by qubit 1
+    /// frames.cx(1, 2);
+    /// let map = [0, 1];
+    /// assert_eq!(frames.dependencies_of(2, &map), Ok(vec![0, 1]));
+    /// assert_eq!(frames.dependencies_of(0, &map), Ok(vec![]));
+    /// assert_eq!(frames.dependencies_of(3, &map), Err(MissingBit(3).into()));
+    /// assert_eq!(
+    ///     frames.dependencies_of(2, &map[..1]),
+    ///     Err(DependencyError::ShortMap { frame: 1, len: 1 })
+    /// );
+    /// # }
+    /// ```
+    pub fn dependencies_of(
+        &self,
+        qubit: usize,
+        map: &[usize],
+    ) -> Result<Vec<usize>, DependencyError> {
+        let stack = self.storage.get(qubit).ok_or(MissingBit(qubit))?;
+        let mut dependencies = stack
+            .z
+            .iter_vals()
+            .enumerate()
+            .chain(stack.x.iter_vals().enumerate())
+            .filter(|(_, flag)| *flag)
+            .map(|(frame, _)| {
+                map.get(frame)
+                    .copied()
+                    .ok_or(DependencyError::ShortMap { frame, len: map.len() })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        dependencies.sort_unstable();
+        dependencies.dedup();
+        Ok(dependencies)
+    }
+
+    /// Get the light cone of `qubit`, i.e., all qubits that are connected to `qubit`
+    /// through shared frames: two qubits are directly connected if both have a
+    /// non-identity Pauli in the same frame, and the light cone is the connected
+    /// component of `qubit` (including `qubit`) in the graph of these connections.
+    ///
+    /// Qubits in different light cones have independent corrections, so the light
+    /// cones can, for example, be used to partition the circuit for parallel
+    /// processing. If `qubit` is not in the tracker, the light cone is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() { #![cfg_attr(coverage_nightly, coverage(off))]
+    /// # use pauli_tracker::{collection::{BufferedVector, Init}, pauli::PauliStack,
+    /// #     tracker::{Tracker, frames::Frames}};
+    /// let mut frames = Frames::<BufferedVector<PauliStack<Vec<bool>>>>::init(4);
+    /// frames.track_x(0);
+    /// frames.cx(0, 1);
+    /// frames.track_z(1);
+    /// frames.cz(1, 2);
+    /// let mut light_cone = frames.light_cone(0).into_iter().collect::<Vec<_>>();
+    /// light_cone.sort_unstable();
+    /// assert_eq!(light_cone, vec![0, 1, 2]);
+    /// assert_eq!(frames.light_cone(3).into_iter().collect::<Vec<_>>(), vec![3]);
+    /// assert!(frames.light_cone(4).is_empty());
+    /// # }
+    /// ```
+    pub fn light_cone(&self, qubit: usize) -> HashSet<usize> {
+        let mut light_cone = HashSet::new();
+        if self.storage.get(qubit).is_none() {
+            return light_cone;
+        }
+        let mut frame_qubits = vec![Vec::new(); self.frames_num];
+        let mut qubit_frames = HashMap::<usize, Vec<usize>>::new();
+        for (bit, stack) in self.storage.iter_pairs() {
+            let frames = stack
+                .z
+                .iter_vals()
+                .zip(stack.x.iter_vals())
+                .enumerate()
+                .filter_map(|(frame, (z, x))| (z | x).then_some(frame))
+                .collect::<Vec<_>>();
+            for &frame in &frames {
+                frame_qubits[frame].push(bit);
+            }
+            qubit_frames.insert(bit, frames);
+        }
+
+        let mut visited_frames = vec![false; self.frames_num];
+        let mut queue = vec![qubit];
+        light_cone.insert(qubit);
+        while let Some(bit) = queue.pop() {
+            for &frame in &qubit_frames[&bit] {
+                if mem::replace(&mut visited_frames[frame], true) {
+                    continue;
+                }
+                for &other in &frame_qubits[frame] {
+                    if light_cone.insert(other) {
+                        queue.push(other);
+                    }
+                }
+            }
+        }
+        light_cone
+    }
+
+    /// Check whether `self` and `other` describe the same frames, independent of their
+    /// storage and [BooleanVector] types.
+    ///
+    /// The stacks are compared via their [canonical
+    /// bytes](PauliStack::canonical_bytes), sorted by the qubits, so, in contrast to
+    /// [PartialEq], this works across different backends, e.g., to compare a
+    /// `Frames<Map<PauliStack<Vec<bool>>>>` with a
+    /// `Frames<MappedVector<PauliStack<BitVec>>>` in tests.
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() { #![cfg_attr(coverage_nightly, coverage(off))]
+    /// # use pauli_tracker::{collection::{BufferedVector, Init, Map}, pauli::PauliStack,
+    /// #     tracker::{Tracker, frames::Frames}};
+    /// let mut a = Frames::<BufferedVector<PauliStack<Vec<bool>>>>::init(2);
+    /// let mut b = Frames::<Map<PauliStack<Vec<bool>>>>::init(2);
+    /// a.track_x(0);
+    /// b.track_x(0);
+    /// a.cx(0, 1);
+    /// b.cx(0, 1);
+    /// assert!(a.logically_eq(&b));
+    /// b.h(1);
+    /// assert!(!a.logically_eq(&b));
+    /// # }
+    /// ```
+    pub fn logically_eq<S2, B2>(&self, other: &Frames<S2>) -> bool
+    where
+        S2: Iterable<TI = PauliStack<B2>>,
+        B2: BooleanVector,
+    {
+        fn canonical<'l, B: BooleanVector + 'l>(
+            stacks: impl Iterator<Item = (usize, &'l PauliStack<B>)>,
+        ) -> Vec<(usize, Vec<u8>)> {
+            let mut stacks = stacks
+                .map(|(bit, stack)| (bit, stack.canonical_bytes()))
+                .collect::<Vec<_>>();
+            stacks.sort_by_key(|(bit, _)| *bit);
+            stacks
+        }
+        self.frames_num == other.frames_num
+            && canonical(self.storage.iter_pairs())
+                == canonical(other.storage.iter_pairs())
+    }
+
+    /// Export the frames in a sparse coordinate list (COO) format: the returned vectors
+    /// contain the qubit, the frame and the [tableau
+    /// encoding](crate::pauli::tableau_encoding) of all non-identity Paulis, ordered by
+    /// the qubits and then by the frames.
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() { #![cfg_attr(coverage_nightly, coverage(off))]
+    /// # use pauli_tracker::{collection::{BufferedVector, Init}, pauli::PauliStack,
+    /// #     tracker::{Tracker, frames::Frames}};
+    /// let mut frames = Frames::<BufferedVector<PauliStack<Vec<bool>>>>::init(2);
+    /// frames.track_x(1);
+    /// frames.track_y(0);
+    /// assert_eq!(frames.to_coo(), (vec![0, 1], vec![1, 0], vec![3, 2]));
+    /// # }
+    /// ```
+    pub fn to_coo(&self) -> (Vec<usize>, Vec<usize>, Vec<u8>) {
+        let (mut qubits, mut frames, mut paulis) = (Vec::new(), Vec::new(), Vec::new());
+        for (bit, stack) in self.storage.sort_by_key() {
+            for (frame, (z, x)) in
+                stack.z.iter_vals().zip(stack.x.iter_vals()).enumerate()
+            {
+                if z | x {
+                    qubits.push(bit);
+                    frames.push(frame);
+                    paulis.push(PauliTuple::new_product(z, x).tableau_encoding());
+                }
+            }
+        }
+        (qubits, frames, paulis)
+    }
+
+    /// Get the pairwise commutation structure of the frames: The entry (i, j) is the
+    /// symplectic product of the frames i and j, i.e., it is true if and only if the
+    /// frames anticommute.
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() { #![cfg_attr(coverage_nightly, coverage(off))]
+    /// # use pauli_tracker::{collection::{BufferedVector, Init}, pauli::PauliStack,
+    /// #     tracker::{Tracker, frames::Frames}};
+    /// let mut frames = Frames::<BufferedVector<PauliStack<Vec<bool>>>>::init(2);
+    /// frames.track_x(0);
+    /// frames.track_z(0);
+    /// frames.track_z(1);
+    /// assert_eq!(frames.frame_commutation_matrix(), vec![
+    ///     vec![false, true, false],
+    ///     vec![true, false, false],
+    ///     vec![false, false, false],
+    /// ]);
+    /// # }
+    /// ```
+    pub fn frame_commutation_matrix(&self) -> Vec<Vec<bool>> {
+        let mut matrix = vec![vec![false; self.frames_num]; self.frames_num];
+        for stack in self.storage.values() {
+            let paulis = stack
+                .z
+                .iter_vals()
+                .zip(stack.x.iter_vals())
+                .enumerate()
+                .filter(|(_, (z, x))| *z | *x)
+                .collect::<Vec<_>>();
+            for (a, &(i, (z_i, x_i))) in paulis.iter().enumerate() {
+                for &(j, (z_j, x_j)) in &paulis[a + 1..] {
+                    let anticommute = (z_i & x_j) ^ (x_i & z_j);
+                    matrix[i][j] ^= anticommute;
+                    matrix[j][i] ^= anticommute;
+                }
+            }
+        }
+        matrix
+    }
+
+    /// Multiply the frame `source` into the frame `target`, neglecting phases, i.e., XOR
+    /// the Paulis of `source` into the Paulis of `target` on all qubits.
+    ///
+    /// # Panics
+    /// Panics if `source` and `target` are the same or if one of them is not smaller
+    /// than [frames_num](Self::frames_num).
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() { #![cfg_attr(coverage_nightly, coverage(off))]
+    /// # use pauli_tracker::{collection::{BufferedVector, Init}, pauli::{Pauli,
+    /// #     PauliStack, PauliTuple}, tracker::{Tracker, frames::Frames}};
+    /// let mut frames = Frames::<BufferedVector<PauliStack<Vec<bool>>>>::init(2);
+    /// frames.track_x(0);
+    /// frames.track_pauli_string(vec![(0, PauliTuple::Z), (1, PauliTuple::X)]);
+    /// frames.xor_frame(1, 0);
+    /// assert_eq!(frames.get_frame(0), Some(vec![(0, PauliTuple::Y), (1, PauliTuple::X)]));
+    /// # }
+    /// ```
+    pub fn xor_frame(&mut self, source: usize, target: usize) {
+        assert_ne!(source, target, "cannot multiply a frame into itself");
+        assert!(
+            source < self.frames_num && target < self.frames_num,
+            "frame index out of bounds"
+        );
+        for stack in self.storage.values_mut() {
+            for vec in [&mut stack.z, &mut stack.x] {
+                if vec.get(source).unwrap_or(false) {
+                    let flag = vec.get(target).unwrap_or(false);
+                    vec.set(target, !flag);
+                }
+            }
+        }
+    }
+
+    /// Compute the reduced (column) echelon form of the frames over GF(2), neglecting
+    /// phases, returning the reduced frames and the rank.
+    ///
+    /// Each frame is viewed as a column of the symplectic tableau, whose rows are the z
+    /// and x components of the qubits, ordered by the qubits. The frames are reduced by
+    /// Gaussian elimination via [xor_frame](Self::xor_frame), so that the
+    /// first `rank` frames of the returned tracker are linearly independent and every
+    /// pivot row has exactly one non-zero entry, while all remaining frames are trivial.
+    /// Hence, a rank smaller than [frames_num](Self::frames_num) means that some frames
+    /// are products of other frames.
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() { #![cfg_attr(coverage_nightly, coverage(off))]
+    /// # use pauli_tracker::{collection::{BufferedVector, Init}, pauli::{Pauli,
+    /// #     PauliStack, PauliTuple}, tracker::{Tracker, frames::Frames}};
+    /// let mut frames = Frames::<BufferedVector<PauliStack<Vec<bool>>>>::init(2);
+    /// frames.track_x(0);
+    /// frames.track_z(1);
+    /// frames.track_pauli_string(vec![(0, PauliTuple::X), (1, PauliTuple::Z)]);
+    /// let (reduced, rank) = frames.rref();
+    /// assert_eq!(rank, 2);
+    /// assert_eq!(reduced.get_frame(2), Some(vec![(0, PauliTuple::I), (1, PauliTuple::I)]));
+    /// # }
+    /// ```
+    pub fn rref(&self) -> (Self, usize)
+    where
+        S: Clone,
+    {
+        let mut reduced = self.clone();
+        let bits = self.storage.sort_by_key().into_iter().map(|(bit, _)| bit);
+        let mut rank = 0;
+        for bit in bits {
+            for x_component in [false, true] {
+                if rank == reduced.frames_num {
+                    return (reduced, rank);
+                }
+                let stack = reduced.storage.get(bit).expect("qubit is in the storage");
+                let row = if x_component {
+                    stack.x.clone()
+                } else {
+                    stack.z.clone()
+                };
+                let Some(pivot) = (rank..reduced.frames_num)
+                    .find(|&frame| row.get(frame).unwrap_or(false))
+                else {
+                    continue;
+                };
+                if pivot != rank {
+                    // the frames from `rank` on are zero on all previous pivot rows, so
+                    // this keeps the reduced form of the previous rows
+                    reduced.xor_frame(pivot, rank);
+                }
+                for frame in (0..reduced.frames_num).filter(|&frame| frame != rank) {
+                    if row.get(frame).unwrap_or(false) {
+                        reduced.xor_frame(rank, frame);
+                    }
+                }
+                rank += 1;
+            }
+        }
+        (reduced, rank)
+    }
+
+    /// Return the approximate number of bytes that are allocated on the heap by all
+    /// [PauliStack]s, cf. [PauliStack::memory_bytes]. The overhead of the storage
+    /// itself is not included.
+    pub fn total_memory_bytes(&self) -> usize {
+        self.storage.values().map(|stack| stack.memory_bytes()).sum()
+    }
+
+    /// Fold all frames with a known measurement outcome into a [Live] correction and
+    /// remove them from the tracker.
+    ///
+    /// `frame_outcomes[i]` describes frame i: If it is [None], the outcome of the
+    /// measurement that induced the frame is still unknown and the frame is kept. If it
+    /// is `Some(true)`, the frame's Paulis are actually present (e.g., the measurement
+    /// outcome requires the correction) and they are multiplied, neglecting phases, into
+    /// the returned [Live] tracker. If it is `Some(false)`, the frame is trivial and it
+    /// is simply dropped. The remaining frames keep their relative order. The returned
+    /// [Live] tracker contains all qubits of `self` (with the identity if no frame
+    /// contributed).
+    ///
+    /// # Panics
+    /// Panics if `frame_outcomes.len()` != [frames_num](Self::frames_num).
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() { #![cfg_attr(coverage_nightly, coverage(off))]
+    /// # use pauli_tracker::{collection::{BufferedVector, Init}, pauli::{Pauli,
+    /// #     PauliStack, PauliTuple}, tracker::{Tracker, frames::Frames, live::Live}};
+    /// let mut frames = Frames::<BufferedVector<PauliStack<Vec<bool>>>>::init(2);
+    /// frames.track_x(0); // frame 0
+    /// frames.track_z(1); // frame 1
+    /// frames.track_y(0); // frame 2
+    /// let live: Live<BufferedVector<PauliTuple>> =
+    ///     frames.apply_outcomes(&[Some(true), None, Some(false)]);
+    /// assert_eq!(frames.frames_num(), 1);
+    /// assert_eq!(frames.get_frame(0), Some(vec![(0, PauliTuple::I), (1, PauliTuple::Z)]));
+    /// assert_eq!(live.get(0), Some(&PauliTuple::X));
+    /// assert_eq!(live.get(1), Some(&PauliTuple::I));
+    /// # }
+    /// ```
+    pub fn apply_outcomes<S2, P>(&mut self, frame_outcomes: &[Option<bool>]) -> Live<S2>
+    where
+        S2: Init + Base<TB = P>,
+        P: Pauli,
+    {
+        assert_eq!(
+            frame_outcomes.len(),
+            self.frames_num,
+            "the number of outcomes must be the number of frames"
+        );
+        let present = frame_outcomes
+            .iter()
+            .map(|outcome| *outcome == Some(true))
+            .collect::<Vec<_>>();
+        let keep = |mask: &B| {
+            mask.iter_vals()
+                .zip(frame_outcomes)
+                .filter_map(|(flag, outcome)| outcome.is_none().then_some(flag))
+                .collect::<B>()
+        };
+
+        let mut correction = S2::init(0);
+        for (bit, stack) in self.storage.iter_pairs_mut() {
+            correction.insert(
+                bit,
+                P::new_product(stack.z.sum_up(&present), stack.x.sum_up(&present)),
+            );
+            *stack = PauliStack {
+                z: keep(&stack.z),
+                x: keep(&stack.x),
+            };
+        }
+        self.frames_num =
+            frame_outcomes.iter().filter(|outcome| outcome.is_none()).count();
+        Live::new(correction)
+    }
+
+    /// Check whether the frames, with the measurement `outcomes` of the frames, sum up
+    /// to the Paulis in `live`, i.e., whether both trackers agree.
+    ///
+    /// Returns the (sorted) qubits of `self` for which the summed up Pauli is not equal
+    /// to the Pauli in `live`, including qubits that are missing in `live`. Qubits in
+    /// `live` that are not in `self` are ignored. This is, for example, useful to check
+    /// a tracking implementation against a reference.
+    ///
+    /// # Panics
+    /// Panics if `outcomes.len()` < [frames_num](Self::frames_num), cf.
+    /// [PauliStack::sum_up].
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() { #![cfg_attr(coverage_nightly, coverage(off))]
+    /// # use pauli_tracker::{collection::{BufferedVector, Init}, pauli::{PauliStack,
+    /// #     PauliTuple}, tracker::{Tracker, frames::Frames, live::Live}};
+    /// let mut frames = Frames::<BufferedVector<PauliStack<Vec<bool>>>>::init(2);
+    /// let mut live = Live::<BufferedVector<PauliTuple>>::init(2);
+    /// frames.track_x(0); // outcome true
+    /// live.track_x(0);
+    /// frames.track_z(1); // outcome false
+    /// frames.cx(0, 1);
+    /// live.cx(0, 1);
+    /// assert_eq!(frames.verify_against_live(&live, &[true, false]), Ok(()));
+    /// assert_eq!(frames.verify_against_live(&live, &[true, true]), Err(vec![0, 1]));
+    /// # }
+    /// ```
+    pub fn verify_against_live<S2, P>(
+        &self,
+        live: &Live<S2>,
+        outcomes: &[bool],
+    ) -> Result<(), Vec<usize>>
+    where
+        S2: Base<TB = P>,
+        P: Pauli,
+    {
+        let mut disagreeing = self
+            .storage
+            .iter_pairs()
+            .filter_map(|(bit, stack)| {
+                let expected = stack.sum_up(outcomes).tableau_encoding();
+                match live.get(bit) {
+                    Some(pauli) if pauli.tableau_encoding() == expected => None,
+                    _ => Some(bit),
+                }
+            })
+            .collect::<Vec<_>>();
+        if disagreeing.is_empty() {
+            Ok(())
+        } else {
+            disagreeing.sort_unstable();
+            Err(disagreeing)
+        }
+    }
+
+    /// Measure a qu`bit` and store the according stack of tracked Paulis into
+    /// `storage`. Errors when the qu`bit` is not present in the tracker.
+    pub fn measure_and_store(
+        &mut self,
+        bit: usize,
+        storage: &mut impl Base<TB = PauliStack<B>>,
+    ) -> Result<(), MoveError<B>> {
+        match storage.insert(bit, self.measure(bit)?) {
+            Some(p) => Err(OverwriteStack { bit, stack: p }.into()),
+            None => Ok(()),
+        }
+    }
+
+    /// "Move" the Z Pauli stacks of all `sources` onto the Z stack of the `destination`
+    /// qubit, i.e., do [move_z_to_z](Tracker::move_z_to_z) for each source, but with
+    /// only a single lookup of the `destination`, e.g., to combine the corrections of
+    /// qubits that are measured together.
+    ///
+    /// As for [move_z_to_z](Tracker::move_z_to_z), the Z stacks of the `sources` are
+    /// cleared, so this should only be used directly before the `sources` are measured.
+    ///
+    /// # Panics
+    /// Panics if one of the qubits is missing or if `destination` is one of the
+    /// `sources`.
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() { #![cfg_attr(coverage_nightly, coverage(off))]
+    /// # use pauli_tracker::{collection::{BufferedVector, Init}, pauli::PauliStack,
+    /// #     tracker::{Tracker, frames::Frames}};
+    /// let mut frames = Frames::<BufferedVector<PauliStack<Vec<bool>>>>::init(3);
+    /// frames.track_z(0);
+    /// frames.track_y(1);
+    /// frames.move_many_z_to_z(&[0, 1], 2);
+    /// assert_eq!(frames.get(2), Some(&PauliStack::try_from_str("11", "00").unwrap()));
+    /// assert_eq!(frames.get(1), Some(&PauliStack::try_from_str("", "01").unwrap()));
+    /// # }
+    /// ```
+    pub fn move_many_z_to_z(&mut self, sources: &[usize], destination: usize) {
+        let mut z = mem::take(
+            &mut unwrap_get_mut!(self.storage, destination, "move_many_z_to_z").z,
+        );
+        for &source in sources {
+            assert_ne!(
+                source, destination,
+                "move_many_z_to_z: {source} is the destination"
+            );
+            let stack = unwrap_get_mut!(self.storage, source, "move_many_z_to_z");
+            z.xor_inplace(&stack.z);
+            stack.z.resize(0, false);
+        }
+        // the destination exists, since we took its stack above
+        self.storage.get_mut(destination).unwrap().z = z;
+    }
+
+    /// Measure a qu`bit` and directly collapse its stack into the net correction,
+    /// interpreting all frames as being triggered by the same `outcome`, i.e., the
+    /// result is the product of all frames on the qu`bit` if `outcome` is true and the
+    /// identity otherwise. Errors when the qu`bit` is not present in the tracker.
+    ///
+    /// In contrast to [measure](Tracker::measure), the stack is dropped, so the
+    /// dependency of the correction on the single frames (e.g., for [induced_order]) is
+    /// lost; if the frames have different outcomes, use [measure](Tracker::measure) and
+    /// [PauliStack::sum_up].
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() { #![cfg_attr(coverage_nightly, coverage(off))]
+    /// # use pauli_tracker::{collection::{BufferedVector, Init}, pauli::{Pauli,
+    /// #     PauliStack, PauliTuple}, tracker::{Tracker, frames::Frames}};
+    /// let mut frames = Frames::<BufferedVector<PauliStack<Vec<bool>>>>::init(2);
+    /// frames.track_x(1);
+    /// frames.track_z(1);
+    /// assert_eq!(frames.measure_collapse(1, true), Ok(PauliTuple::Y));
+    /// assert_eq!(frames.measure_collapse(0, false), Ok(PauliTuple::I));
+    /// # }
+    /// ```
+    pub fn measure_collapse(
+        &mut self,
+        bit: usize,
+        outcome: bool,
+    ) -> Result<PauliTuple, MissingBit> {
+        let stack = self.measure(bit)?;
+        Ok(stack.sum_up(&vec![outcome; self.frames_num]))
+    }
+
+    /// Transpose the frames with sorted qubits. The result is a non-sparse matrix of
+    /// Paulis.
+    ///
+    /// Depending on the use case, [stacked_transpose](Self::stacked_transpose) can be
+    /// more efficient.
+    ///
+    /// # Panics
+    /// Panics if `num_qubits` is smaller the highest qubit index that has been tracked.
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() { #![cfg_attr(coverage_nightly, coverage(off))]
+    /// # use pauli_tracker::{collection::NaiveVector, pauli::{self, PauliTuple},
+    /// #     tracker::frames::Frames};
+    /// type PauliStack = pauli::PauliStack<Vec<bool>>;
+    ///
+    /// let frames = Frames::<NaiveVector<_>>::new_unchecked(
+    ///     vec![
+    ///         //               frame  Z 12  X 12             qubit
+    ///         PauliStack::try_from_str("10", "01").unwrap(), // 0
+    ///         PauliStack::try_from_str("11", "10").unwrap(), // 1
+    ///         PauliStack::try_from_str("11", "01").unwrap(), // 2
+    ///     ]
+    ///     .into(),
+    ///     2,
+    /// );
+    ///
+    /// let transposed = vec![
+    ///     // bit  0       1       2        frame
+    ///     vec![(1, 0), (1, 1), (1, 0)], // 1
+    ///     vec![(0, 1), (1, 0), (1, 1)], // 2
+    /// ]
+    /// .into_iter()
+    /// .map(|frame| frame.into_iter().map(|(z, x)| (z == 1, x == 1)))
+    /// .map(|frame| frame.map(PauliTuple::from).collect::<Vec<PauliTuple>>())
+    /// .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(frames.transpose::<PauliTuple>(3), transposed);
+    /// # }
+    /// ```
+    // for efficiency, one should flatten the matrix internally, but the matrix is not
+    // really used in matrix operations (usually, I think), so to have a return type with
+    // a more flexibel API, we don't do that
+    pub fn transpose<P: Pauli + Clone>(&self, num_qubits: usize) -> Vec<Vec<P>> {
+        let mut ret = Vec::with_capacity(self.frames_num);
+        for i in 0..self.frames_num {
+            ret.push(self.dense_frame(i, num_qubits));
+        }
+        ret
+    }
+
+    /// Consume the tracker into an iterator over the frames, in chronological order,
+    /// where the frames are dense as in [transpose](Self::transpose), i.e., collecting
+    /// the iterator is equivalent to [transpose](Self::transpose). However, the frames
+    /// are built lazily, so the whole matrix does not have to be materialized at once.
+    ///
+    /// # Panics
+    /// Panics (when iterating) if `num_qubits` is smaller the highest qubit index that
+    /// has been tracked.
+    pub fn into_frames<P: Pauli + Clone>(
+        self,
+        num_qubits: usize,
+    ) -> impl ExactSizeIterator<Item = Vec<P>> {
+        (0..self.frames_num).map(move |i| self.dense_frame(i, num_qubits))
+    }
+
+    // one row of the transpose; shared by the serial and parallel version, so that they
+    // are guaranteed to produce the same output
+    fn dense_frame<P: Pauli + Clone>(&self, idx: usize, num_qubits: usize) -> Vec<P> {
+        let frame = self
+            .get_frame::<P>(idx)
+            .expect("frames_num bigger than actual number of frames");
+        let mut paulis = vec![P::I; num_qubits];
+        for (i, p) in frame {
+            paulis[i] = p;
+        }
+        paulis
+    }
+
+    /// Similar to [transpose](Self::transpose), but use [PauliStack] for the frames.
+    ///
+    /// # Panics
+    /// Panics if `num_qubits` is smaller the highest qubit index that has been tracked.
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() { #![cfg_attr(coverage_nightly, coverage(off))]
+    /// # use pauli_tracker::{collection::NaiveVector, pauli::{self, PauliTuple},
+    /// #     tracker::frames::Frames};
+    /// type PauliStack = pauli::PauliStack<Vec<bool>>;
+    /// assert_eq!(
+    ///     Frames::<NaiveVector<_>>::new_unchecked(vec![
+    ///         PauliStack::try_from_str("10", "01").unwrap(),
+    ///         PauliStack::try_from_str("11", "10").unwrap(),
+    ///         PauliStack::try_from_str("11", "01").unwrap(),
+    ///     ].into(), 2).stacked_transpose(3),
+    ///     vec![
+    ///         PauliStack::try_from_str("111", "010").unwrap(),
+    ///         PauliStack::try_from_str("011", "101").unwrap(),
+    ///     ]
+    /// );
+    /// # }
+    pub fn stacked_transpose(&self, num_qubits: usize) -> Vec<PauliStack<B>> {
+        let mut ret = Vec::with_capacity(self.frames_num);
+        for i in 0..self.frames_num {
+            let frame = self
+                .get_frame::<PauliTuple>(i)
+                .expect("frames_num bigger than actual number of frames");
+            let mut stack = PauliStack::<B>::zeros(num_qubits);
+            for (i, p) in frame {
+                stack.z.set(i, p.0);
+                stack.x.set(i, p.1);
+            }
+            ret.push(stack);
+        }
+        ret
+    }
+}
+
+#[cfg(feature = "rayon")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+impl<S, B> Frames<S>
+where
+    S: IterableBase<T = PauliStack<B>> + Sync,
+    B: BooleanVector + Sync,
+{
+    /// Parallel version of [transpose](Self::transpose), using [rayon] to build the
+    /// frames (the rows of the output matrix) independently of each other. The output is
+    /// identical to the output of [transpose](Self::transpose).
+    ///
+    /// This is only worth it for really big trackers, e.g., when post-processing large
+    /// MBQC patterns.
+    ///
+    /// # Panics
+    /// Panics if `num_qubits` is smaller the highest qubit index that has been tracked.
+    ///
+    /// [rayon]: https://docs.rs/rayon/latest/rayon/
+    pub fn par_transpose<P: Pauli + Clone + Send>(
+        &self,
+        num_qubits: usize,
+    ) -> Vec<Vec<P>> {
+        (0..self.frames_num)
+            .into_par_iter()
+            .map(|i| self.dense_frame(i, num_qubits))
+            .collect()
+    }
+}
+
+#[cfg(feature = "stim")]
+#[cfg_attr(docsrs, doc(cfg(feature = "stim")))]
+impl<S, B> Frames<S>
+where
+    S: IterableBase<T = PauliStack<B>>,
+    B: BooleanVector,
+{
+    /// Get the frames as Pauli strings in [stim]'s text format, e.g., to cross-check
+    /// them with stim's tableau simulator. The character at position `i` after the sign
+    /// is the Pauli on qubit `i`, with '_' for the identity. Since phases are neglected,
+    /// the sign is always '+'.
+    ///
+    /// # Panics
+    /// Panics if `num_qubits` is smaller the highest qubit index that has been tracked.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[cfg_attr(coverage_nightly, coverage(off))]
+    /// # #[cfg(feature = "stim")]
+    /// # fn main() {
+    /// # use pauli_tracker::{collection::{BufferedVector, Init}, pauli::PauliStack,
+    /// #     tracker::{Tracker, frames::Frames}};
+    /// let mut frames = Frames::<BufferedVector<PauliStack<Vec<bool>>>>::init(3);
+    /// frames.track_x(0);
+    /// frames.cx(0, 2);
+    /// frames.track_y(1);
+    /// assert_eq!(frames.to_stim_strings(3), vec!["+X_X", "+_Y_"]);
+    /// # }
+    /// # #[cfg_attr(coverage_nightly, coverage(off))]
+    /// # #[cfg(not(feature = "stim"))]
+    /// # fn main() {}
+    /// ```
+    ///
+    /// [stim]: https://github.com/quantumlib/Stim
+    pub fn to_stim_strings(&self, num_qubits: usize) -> Vec<String> {
+        (0..self.frames_num)
+            .map(|idx| {
+                let mut string = String::with_capacity(num_qubits + 1);
+                string.push('+');
+                let frame = self.dense_frame::<PauliTuple>(idx, num_qubits);
+                string.extend(frame.into_iter().map(|pauli| {
+                    match (pauli.get_z(), pauli.get_x()) {
+                        (false, false) => '_',
+                        (false, true) => 'X',
+                        (true, true) => 'Y',
+                        (true, false) => 'Z',
+                    }
+                }));
+                string
+            })
+            .collect()
+    }
+
+    /// Push a complete frame, given as a Pauli string in [stim]'s text format, e.g., as
+    /// returned by [to_stim_strings](Self::to_stim_strings).
+    ///
+    /// An optional leading sign ('+' or '-', optionally followed by 'i') is ignored,
+    /// since phases are neglected, and both 'I' and '_' are accepted as identity;
+    /// otherwise, this is the same as [push_frame_str](Self::push_frame_str), including
+    /// the errors, where the positions are counted after the sign.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[cfg_attr(coverage_nightly, coverage(off))]
+    /// # #[cfg(feature = "stim")]
+    /// # fn main() {
+    /// # use pauli_tracker::{collection::{BufferedVector, Init}, pauli::{PauliStack,
+    /// #     Pauli, PauliTuple}, tracker::frames::Frames};
+    /// let mut frames = Frames::<BufferedVector<PauliStack<Vec<bool>>>>::init(3);
+    /// frames.push_frame_stim_str("-iX_Y").unwrap();
+    /// assert_eq!(
+    ///     frames.get_frame(0),
+    ///     Some(vec![(0, PauliTuple::X), (1, PauliTuple::I), (2, PauliTuple::Y)])
+    /// );
+    /// # }
+    /// # #[cfg_attr(coverage_nightly, coverage(off))]
+    /// # #[cfg(not(feature = "stim"))]
+    /// # fn main() {}
+    /// ```
+    ///
+    /// [stim]: https://github.com/quantumlib/Stim
+    pub fn push_frame_stim_str(&mut self, s: &str) -> Result<(), FrameShapeError> {
+        let paulis = s.strip_prefix(['+', '-']).unwrap_or(s);
+        let paulis = paulis.strip_prefix('i').unwrap_or(paulis);
+        self.push_frame_str(&paulis.replace('_', "I"))
+    }
+}
+
+impl<S, B> Frames<S>
+where
+    S: Full<T = PauliStack<B>> + Default,
+    B: BooleanVector,
+{
+    /// Measure all qubits and put the according stack of Paulis into `storage`, i.e.,
+    /// do [Frames::measure_and_store] for all qubits.
+    pub fn measure_and_store_all(&mut self, storage: &mut impl Base<TB = PauliStack<B>>) {
+        for (bit, pauli) in
+            // mem::replace(&mut self.storage, S::init(0, PauliStack::default()))
+            mem::take(&mut self.storage).into_iter()
+        {
+            storage.insert(bit, pauli);
+        }
+    }
+
+    /// Remove the `qubits` from the tracker, returning the removed stacks sorted by the
+    /// qubits; qubits that are not in the tracker are skipped.
+    ///
+    /// The qubits are removed in descending order, so that, e.g., a [BufferedVector]
+    /// storage, which only allows removing the last element, supports removing any set
+    /// of trailing qubits in one call. [frames_num](Self::frames_num) is unchanged,
+    /// i.e., the frames remain (and can still be induced by the removed qubits).
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() { #![cfg_attr(coverage_nightly, coverage(off))]
+    /// # use pauli_tracker::{collection::{BufferedVector, Init}, pauli::PauliStack,
+    /// #     tracker::{Tracker, frames::Frames}};
+    /// let mut frames = Frames::<BufferedVector<PauliStack<Vec<bool>>>>::init(4);
+    /// frames.track_x(2);
+    /// frames.track_z(3);
+    /// let removed = frames.remove_qubits(&[3, 2, 7]);
+    /// assert_eq!(removed, vec![
+    ///     (2, PauliStack::try_from_str("00", "10").unwrap()),
+    ///     (3, PauliStack::try_from_str("01", "00").unwrap()),
+    /// ]);
+    /// assert_eq!(frames.get(2), None);
+    /// assert_eq!(frames.frames_num(), 2);
+    /// # }
+    /// ```
+    ///
+    /// [BufferedVector]: crate::collection::BufferedVector
+    pub fn remove_qubits(&mut self, qubits: &[usize]) -> Vec<(usize, PauliStack<B>)> {
+        let mut qubits = qubits.to_vec();
+        qubits.sort_unstable();
+        qubits.dedup();
+        let mut removed = qubits
+            .into_iter()
+            .rev()
+            .filter_map(|bit| Some((bit, self.storage.remove(bit)?)))
+            .collect::<Vec<_>>();
+        removed.reverse();
+        removed
+    }
+}
+
+/// A [Frames] tracker that additionally records the origin of each frame, i.e., the
+/// qubit on whose measurement outcome the frame is conditioned.
+///
+/// This is opt-in, since [Frames] alone does not know the origins: the qubit on which a
+/// Pauli is tracked is in general not the qubit whose measurement induces the Pauli.
+/// The recorded origins are exactly the `map` that is required by
+/// [induced_order::get_order], cf. [induced_order](Self::induced_order).
+///
+/// New frames have to be tracked via the methods of this type (and not via
+/// [frames_mut](Self::frames_mut)), otherwise the origins get out of sync with the
+/// frames.
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FramesWithOrigins<Storage> {
+    frames: Frames<Storage>,
+    origins: Vec<usize>,
+}
+
+impl<S> FramesWithOrigins<S> {
+    /// Create a new [FramesWithOrigins] instance from `frames` and their `origins`.
+    ///
+    /// It does not check whether the number of `origins` is the number of frames in
+    /// `frames`, compare [Frames::new_unchecked].
+    pub fn new_unchecked(frames: Frames<S>, origins: Vec<usize>) -> Self {
+        Self { frames, origins }
+    }
+
+    /// Reference the underlining [Frames] tracker.
+    pub fn frames(&self) -> &Frames<S> {
+        &self.frames
+    }
+
+    /// Mutably reference the underlining [Frames] tracker, e.g., to apply gates.
+    ///
+    /// Do not track or remove frames through this reference; use the methods of
+    /// [FramesWithOrigins] instead.
+    pub fn frames_mut(&mut self) -> &mut Frames<S> {
+        &mut self.frames
+    }
+
+    /// Get the origins of the frames, i.e., the i-th element is the qubit the i-th
+    /// frame depends on.
+    pub fn frame_origins(&self) -> &[usize] {
+        &self.origins
+    }
+
+    /// Convert the object into the underlining [Frames] tracker and the origins.
+    pub fn into_parts(self) -> (Frames<S>, Vec<usize>) {
+        (self.frames, self.origins)
+    }
+}
+
+impl<S: Init> Init for FramesWithOrigins<S> {
+    fn init(len: usize) -> Self {
+        Self {
+            frames: Frames::init(len),
+            origins: Vec::new(),
+        }
+    }
+}
+
+impl<S, B> FramesWithOrigins<S>
+where
+    S: IterableBase<T = PauliStack<B>>,
+    B: BooleanVector,
+{
+    /// Track a new frame consisting of the Pauli `pauli` at qu`bit`, which depends on
+    /// the measurement of qu`origin`, cf. [Tracker::track_pauli].
+    ///
+    /// If the tracker is empty, nothing is tracked and the origin is not recorded.
+    pub fn track_pauli(&mut self, origin: usize, bit: usize, pauli: PauliTuple) {
+        let frames_num = self.frames.frames_num;
+        self.frames.track_pauli(bit, pauli);
+        if self.frames.frames_num > frames_num {
+            self.origins.push(origin);
+        }
+    }
+
+    /// Track a new frame including multiple Paulis, which depends on the measurement of
+    /// qu`origin`, cf. [Tracker::track_pauli_string].
+    pub fn track_pauli_string(&mut self, origin: usize, string: PauliString<PauliTuple>) {
+        let frames_num = self.frames.frames_num;
+        self.frames.track_pauli_string(string);
+        if self.frames.frames_num > frames_num {
+            self.origins.push(origin);
+        }
+    }
+
+    /// Pop the last tracked Pauli frame together with its origin, cf.
+    /// [Frames::pop_frame].
+    pub fn pop_frame<P: Pauli>(&mut self) -> Option<(usize, PauliString<P>)> {
+        let frame = self.frames.pop_frame()?;
+        Some((self.origins.pop().expect("origins out of sync with frames"), frame))
+    }
+
+    /// Get the induced time ordering of the qubits, using the recorded origins as `map`
+    /// for [induced_order::get_order], including its panics.
+    pub fn induced_order(&self) -> induced_order::PartialOrderGraph {
+        induced_order::get_order(self.frames.storage.iter_pairs(), &self.origins)
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serialization {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer, de};
+
+    use super::{Frames, SERDE_VERSION};
+
+    #[derive(Serialize, Deserialize)]
+    struct Header {
+        version: u32,
+    }
+
+    #[derive(Serialize)]
+    #[serde(rename = "Frames")]
+    struct VersionedRef<'l, S> {
+        header: Header,
+        storage: &'l S,
+        frames_num: usize,
+    }
+
+    #[derive(Deserialize)]
+    #[serde(rename = "Frames")]
+    struct Versioned<S> {
+        header: Header,
+        storage: S,
+        frames_num: usize,
+    }
+
+    // the representation before we had the header; only for human-readable formats,
+    // since the binary formats are not self-describing
+    #[derive(Deserialize)]
+    #[serde(rename = "Frames")]
+    struct Legacy<S> {
+        #[serde(default)]
+        header: Option<Header>,
+        storage: S,
+        frames_num: usize,
+    }
+
+    impl<S: Serialize> Serialize for Frames<S> {
+        fn serialize<Ser: Serializer>(
+            &self,
+            serializer: Ser,
+        ) -> Result<Ser::Ok, Ser::Error> {
+            VersionedRef {
+                header: Header { version: SERDE_VERSION },
+                storage: &self.storage,
+                frames_num: self.frames_num,
+            }
+            .serialize(serializer)
+        }
+    }
+
+    impl<'de, S: Deserialize<'de>> Deserialize<'de> for Frames<S> {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let (header, storage, frames_num) = if deserializer.is_human_readable() {
+                let Legacy { header, storage, frames_num } =
+                    Legacy::deserialize(deserializer)?;
+                (header, storage, frames_num)
+            } else {
+                let Versioned { header, storage, frames_num } =
+                    Versioned::deserialize(deserializer)?;
+                (Some(header), storage, frames_num)
+            };
+            if let Some(Header { version }) = header {
+                if version != SERDE_VERSION {
+                    return Err(de::Error::custom(format!(
+                        "the Frames data has version {version}, but this version of the \
+                         library requires version {SERDE_VERSION}"
+                    )));
+                }
+            }
+            Ok(Frames { storage, frames_num })
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use coverage_helper::test;
+
+        use super::*;
+        use crate::{
+            collection::{BufferedVector, Init},
+            pauli::PauliStack,
+            tracker::Tracker,
+        };
+
+        type ThisFrames = Frames<BufferedVector<PauliStack<Vec<bool>>>>;
+
+        #[cfg_attr(coverage_nightly, coverage(off))]
+        fn frames() -> ThisFrames {
+            let mut frames = ThisFrames::init(2);
+            frames.track_x(0);
+            frames.cx(0, 1);
+            frames.track_z(1);
+            frames
+        }
+
+        #[test]
+        fn json() {
+            let frames = frames();
+            let json = serde_json::to_string(&frames).unwrap();
+            assert_eq!(
+                json,
+                r#"{"header":{"version":1},"storage":["XI","XZ"],"frames_num":2}"#
+            );
+            assert_eq!(serde_json::from_str::<ThisFrames>(&json).unwrap(), frames);
+            assert_eq!(
+                serde_json::from_str::<ThisFrames>(
+                    r#"{"storage":["XI","XZ"],"frames_num":2}"#
+                )
+                .unwrap(),
+                frames
+            );
+        }
+
+        #[test]
+        fn binary() {
+            let frames = frames();
+            let bytes = bincode::serialize(&frames).unwrap();
+            assert_eq!(&bytes[..4], &SERDE_VERSION.to_le_bytes());
+            assert_eq!(bincode::deserialize::<ThisFrames>(&bytes).unwrap(), frames);
+        }
+
+        #[test]
+        fn version_mismatch() {
+            let error = serde_json::from_str::<ThisFrames>(
+                r#"{"header":{"version":0},"storage":["XI","XZ"],"frames_num":2}"#,
+            )
+            .unwrap_err();
+            assert!(error.to_string().contains("version 0"), "{error}");
+
+            let mut bytes = bincode::serialize(&frames()).unwrap();
+            bytes[..4].copy_from_slice(&(SERDE_VERSION + 1).to_le_bytes());
+            let error = bincode::deserialize::<ThisFrames>(&bytes).unwrap_err();
+            assert!(
+                error.to_string().contains(&format!("version {}", SERDE_VERSION + 1)),
+                "{error}"
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use coverage_helper::test;
+
+    use super::*;
+
+    // we only check the basic functionality here, more complicated circuits are tested
     // in [super::circuit] to test the tracker and the circuit at once
 
     mod action_definition_check {
@@ -565,4 +2120,927 @@ mod tests {
             utils::double_check(runner, ACTIONS);
         }
     }
+
+    #[test]
+    fn from_stacks() {
+        let mut frames = Frames::<BufferedVector<PauliStack<Vec<bool>>>>::init(3);
+        frames.track_x(0);
+        frames.track_z(2);
+        frames.cx(0, 1);
+        frames.h(2);
+        frames.track_y(1);
+        frames.cz(1, 2);
+        let stacks = frames.as_storage().0.clone();
+        assert_eq!(Frames::from_stacks(stacks), Ok(frames));
+
+        assert_eq!(
+            Frames::<BufferedVector<PauliStack<Vec<bool>>>>::from_stacks(vec![]),
+            Ok(Frames::init(0))
+        );
+        assert_eq!(
+            Frames::from_stacks(vec![
+                PauliStack::<Vec<bool>>::zeros(2),
+                PauliStack::<Vec<bool>>::try_from_str("00", "000").unwrap(),
+            ]),
+            Err(InconsistentFrames { bit: 1, expected: 2, found: 3 })
+        );
+        assert_eq!(
+            Frames::from_stacks(vec![
+                PauliStack::<Vec<bool>>::zeros(2),
+                PauliStack::<Vec<bool>>::try_from_str("0", "000").unwrap(),
+            ]),
+            Err(InconsistentFrames { bit: 1, expected: 2, found: 1 })
+        );
+    }
+
+    #[test]
+    fn checkpoint() {
+        use crate::collection::Map;
+
+        let mut frames = Frames::<Map<PauliStack<Vec<bool>>>>::init(3);
+        frames.track_x(0);
+        frames.cx(0, 1);
+        frames.h(1);
+        frames.track_z(2);
+        let checkpoint = frames.checkpoint();
+        let check = frames.clone();
+
+        frames.track_y(1);
+        frames.track_x(2);
+        frames.track_z(0);
+        assert_eq!(frames.frames_num(), 5);
+        frames.restore(checkpoint);
+        assert_eq!(frames, check);
+
+        // gates that act trivially on the old frames
+        frames.track_x(1);
+        frames.cz(1, 2);
+        frames.restore(checkpoint);
+        assert_eq!(frames, check);
+    }
+
+    #[test]
+    fn grow_frames_to() {
+        let mut frames = Frames::<BufferedVector<PauliStack<Vec<bool>>>>::init(3);
+        frames.track_x(0);
+        frames.track_z(2);
+        let check = frames.clone();
+
+        frames.grow_frames_to(5);
+        assert_eq!(frames.frames_num(), 5);
+        for bit in 0..3 {
+            let stack = frames.get(bit).unwrap();
+            assert_eq!(stack.z.len(), 5);
+            assert_eq!(stack.x.len(), 5);
+        }
+        for idx in 2..5 {
+            let frame = frames.get_frame::<PauliTuple>(idx).unwrap();
+            assert!(frame.iter().all(|(_, p)| *p == PauliTuple::I));
+        }
+        frames.restore(check.checkpoint());
+        assert_eq!(frames, check);
+
+        // shrinking is not possible
+        frames.grow_frames_to(1);
+        assert_eq!(frames, check);
+    }
+
+    #[test]
+    fn from_pauli_strings() {
+        type ThisFrames = Frames<BufferedVector<PauliStack<bit_vec::BitVec>>>;
+        let mut frames = ThisFrames::init(4);
+        frames.track_x(0);
+        frames.cx(0, 2);
+        frames.track_y(3);
+        frames.h(3);
+        frames.track_z(1);
+        frames.cz(1, 0);
+        frames.track_pauli_string(vec![(1, PauliTuple::Y), (2, PauliTuple::X)]);
+
+        let transposed = frames.transpose::<PauliTuple>(4);
+        let strings = transposed
+            .iter()
+            .map(|frame| frame.iter().copied().enumerate().collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        let rebuilt = ThisFrames::from_pauli_strings(4, &strings);
+        assert_eq!(rebuilt, frames);
+        assert_eq!(rebuilt.transpose::<PauliTuple>(4), transposed);
+
+        // sparse strings; Paulis on unknown qubits are ignored
+        let rebuilt = ThisFrames::from_pauli_strings(2, &[
+            vec![(1, PauliTuple::Z), (5, PauliTuple::X)],
+            vec![],
+        ]);
+        assert_eq!(rebuilt.frames_num(), 2);
+        assert_eq!(rebuilt.transpose::<PauliTuple>(2), vec![
+            vec![PauliTuple::I, PauliTuple::Z],
+            vec![PauliTuple::I, PauliTuple::I],
+        ]);
+    }
+
+    #[test]
+    fn reserve_frames() {
+        const FRAMES: usize = 200;
+
+        #[cfg_attr(coverage_nightly, coverage(off))]
+        fn check<B: BooleanVector>() {
+            let mut frames = Frames::<BufferedVector<PauliStack<B>>>::init(3);
+            frames.track_x(0);
+            frames.reserve_frames(FRAMES);
+            let capacities = |frames: &Frames<BufferedVector<PauliStack<B>>>| {
+                (0..3)
+                    .map(|bit| frames.get(bit).unwrap())
+                    .map(|stack| (stack.z.capacity_bytes(), stack.x.capacity_bytes()))
+                    .collect::<Vec<_>>()
+            };
+            let reserved = capacities(&frames);
+            for (z, x) in reserved.iter() {
+                assert!(*z >= (FRAMES + 1) / 8 && *x >= (FRAMES + 1) / 8);
+            }
+            for i in 0..FRAMES {
+                frames.track_y(i % 3);
+            }
+            assert_eq!(capacities(&frames), reserved);
+        }
+
+        check::<Vec<bool>>();
+        check::<bitvec::vec::BitVec>();
+        check::<bit_vec::BitVec>();
+    }
+
+    #[test]
+    fn dependencies_of() {
+        let mut frames = Frames::<BufferedVector<PauliStack<Vec<bool>>>>::init(4);
+        frames.track_x(1);
+        frames.cx(1, 2);
+        frames.track_z(3);
+        frames.h(3);
+        let map = [0, 1];
+
+        let graph = induced_order::get_order(frames.as_storage(), &map);
+        assert_eq!(graph.len(), 3);
+        for (bit, mut deps) in graph.into_iter().flatten() {
+            deps.sort();
+            assert_eq!(frames.dependencies_of(bit, &map), Ok(deps));
+        }
+        assert_eq!(frames.dependencies_of(4, &map), Err(MissingBit(4).into()));
+        // the frame without a mapping is found, no matter whether it is z or x
+        assert_eq!(
+            frames.dependencies_of(3, &map[..1]),
+            Err(DependencyError::ShortMap { frame: 1, len: 1 })
+        );
+        assert_eq!(
+            frames.dependencies_of(2, &[]),
+            Err(DependencyError::ShortMap { frame: 0, len: 0 })
+        );
+        assert_eq!(frames.dependencies_of(0, &[]), Ok(vec![]));
+    }
+
+    #[test]
+    fn light_cone() {
+        let mut frames = Frames::<BufferedVector<PauliStack<Vec<bool>>>>::init(6);
+        // first cluster: 0 - 2 - 4, where 0 and 4 are only connected via 2
+        frames.track_pauli_string(vec![(0, PauliTuple::X), (2, PauliTuple::X)]);
+        frames.track_pauli_string(vec![(2, PauliTuple::Z), (4, PauliTuple::Y)]);
+        // second cluster: 1 - 3
+        frames.track_y(1);
+        frames.cy(1, 3);
+        // 5 is never touched
+
+        let first = HashSet::from_iter([0, 2, 4]);
+        let second = HashSet::from_iter([1, 3]);
+        for bit in [0, 2, 4] {
+            assert_eq!(frames.light_cone(bit), first);
+        }
+        for bit in [1, 3] {
+            assert_eq!(frames.light_cone(bit), second);
+        }
+        assert_eq!(frames.light_cone(5), HashSet::from_iter([5]));
+        assert_eq!(frames.light_cone(6), HashSet::new());
+    }
+
+    #[test]
+    fn debug() {
+        type ThisFrames = Frames<BufferedVector<PauliStack<Vec<bool>>>>;
+        let mut frames = ThisFrames::init(2);
+        frames.track_y(0);
+        assert_eq!(
+            format!("{frames:?}"),
+            "Frames { storage: BufferedVector([PauliStack { z: [true], x: [true] }, \
+             PauliStack { z: [false], x: [false] }]), frames_num: 1 }"
+        );
+
+        let mut frames = ThisFrames::init(100);
+        for bit in 0..20 {
+            frames.track_x(bit);
+        }
+        let debug = format!("{frames:?}");
+        assert_eq!(debug, "Frames { storage: .., frames_num: 20 }");
+        assert!(format!("{frames:#?}").len() > 100 * 20);
+
+        // no Base bound required
+        #[derive(Debug)]
+        struct NoBase;
+        assert_eq!(
+            format!("{:?}", Frames::new_unchecked(NoBase, 0)),
+            "Frames { storage: NoBase, frames_num: 0 }"
+        );
+    }
+
+    #[test]
+    fn logically_eq() {
+        #[cfg_attr(coverage_nightly, coverage(off))]
+        fn program(frames: &mut impl Tracker<Pauli = PauliTuple>) {
+            frames.track_x(0);
+            frames.cx(0, 2);
+            frames.track_pauli_string(vec![(1, PauliTuple::Y), (3, PauliTuple::Z)]);
+            frames.iswap(1, 2);
+            frames.track_z(3);
+            frames.sh(0);
+        }
+
+        let mut map = Frames::<crate::collection::Map<PauliStack<Vec<bool>>>>::init(4);
+        let mut mapped = Frames::<
+            crate::collection::MappedVector<PauliStack<bitvec::vec::BitVec>>,
+        >::init(4);
+        program(&mut map);
+        program(&mut mapped);
+        assert!(map.logically_eq(&mapped));
+        assert!(mapped.logically_eq(&map));
+
+        // same Paulis, but different number of frames
+        let mut grown = mapped.clone();
+        grown.grow_frames_to(4);
+        assert!(!map.logically_eq(&grown));
+        // a different Pauli
+        mapped.h(3);
+        assert!(!map.logically_eq(&mapped));
+        // a different qubit
+        let mut removed = map.clone();
+        removed.measure(3).unwrap();
+        assert!(!map.logically_eq(&removed));
+    }
+
+    #[test]
+    fn track_pauli_fast_path() {
+        // the contiguous storages use Iterable::for_each_value_mut's fast path, the Map
+        // uses the generic default implementation
+        #[cfg_attr(coverage_nightly, coverage(off))]
+        fn program<S: Init + Full<T = PauliStack<Vec<bool>>>>() -> Vec<Vec<PauliTuple>> {
+            let mut frames = Frames::<S>::init(4);
+            frames.track_x(0);
+            frames.track_y(3);
+            frames.track_pauli(7, PauliTuple::new_z()); // missing qubit
+            frames.track_pauli_string(vec![
+                (1, PauliTuple::new_z()),
+                (2, PauliTuple::new_y()),
+            ]);
+            frames.cx(0, 2);
+            frames.track_z(2);
+            frames.h(1);
+            frames.track_x(1);
+            (0..frames.frames_num())
+                .map(|frame| {
+                    let mut frame = frames.get_frame(frame).unwrap();
+                    frame.sort_by_key(|(bit, _)| *bit);
+                    frame.into_iter().map(|(_, pauli)| pauli).collect()
+                })
+                .collect()
+        }
+
+        let generic = program::<crate::collection::Map<PauliStack<Vec<bool>>>>();
+        assert_eq!(generic.len(), 6);
+        assert_eq!(program::<BufferedVector<PauliStack<Vec<bool>>>>(), generic);
+        assert_eq!(
+            program::<crate::collection::NaiveVector<PauliStack<Vec<bool>>>>(),
+            generic
+        );
+    }
+
+    #[test]
+    fn rref() {
+        let mut frames = Frames::<BufferedVector<PauliStack<Vec<bool>>>>::init(3);
+        frames.track_x(0);
+        frames.track_pauli_string(vec![(1, PauliTuple::Z), (2, PauliTuple::Y)]);
+        // the product of the first two frames
+        frames.track_pauli_string(vec![
+            (0, PauliTuple::X),
+            (1, PauliTuple::Z),
+            (2, PauliTuple::Y),
+        ]);
+        frames.track_z(0);
+        // the product of the first and the fourth frame
+        frames.track_y(0);
+        frames.cx(0, 2);
+        frames.h(1);
+
+        let (reduced, rank) = frames.rref();
+        assert_eq!(rank, 3);
+        assert_eq!(reduced.frames_num(), frames.frames_num());
+        for idx in rank..reduced.frames_num() {
+            let frame = reduced.get_frame::<PauliTuple>(idx).unwrap();
+            assert!(frame.iter().all(|(_, p)| *p == PauliTuple::I));
+        }
+        // the reduced frames span the same space
+        let mut combined = reduced.clone();
+        for idx in 0..frames.frames_num() {
+            combined.track_pauli_string(frames.get_frame(idx).unwrap());
+        }
+        assert_eq!(combined.rref().1, rank);
+        assert_eq!(reduced.rref(), (reduced.clone(), rank));
+
+        let mut independent = Frames::<BufferedVector<PauliStack<Vec<bool>>>>::init(2);
+        independent.track_x(0);
+        independent.track_z(1);
+        independent.track_y(1);
+        assert_eq!(independent.rref().1, 3);
+    }
+
+    #[test]
+    fn move_many_z_to_z() {
+        type ThisFrames = Frames<BufferedVector<PauliStack<Vec<bool>>>>;
+        let mut frames = ThisFrames::init(4);
+        frames.track_z(0);
+        frames.track_y(1);
+        frames.cx(3, 1);
+        frames.track_z(3);
+        frames.cz(0, 3);
+        frames.track_x(1);
+        let mut check = frames.clone();
+
+        frames.move_many_z_to_z(&[0, 1], 3);
+        check.move_z_to_z(0, 3);
+        check.move_z_to_z(1, 3);
+        assert_eq!(frames, check);
+        assert!(frames.get(0).unwrap().z.is_empty());
+        assert_eq!(frames.get(1).unwrap().x, [false, true, false, true]);
+
+        frames.move_many_z_to_z(&[], 2);
+        assert_eq!(frames, check);
+    }
+
+    #[test]
+    fn remove_qubits() {
+        type ThisFrames = Frames<crate::collection::MappedVector<PauliStack<Vec<bool>>>>;
+        let mut frames = ThisFrames::init(5);
+        frames.track_x(0);
+        frames.cx(0, 3);
+        frames.track_z(4);
+        frames.cz(4, 1);
+        let mut check = frames.clone();
+
+        let removed = frames.remove_qubits(&[3, 1, 3, 9]);
+        assert_eq!(removed, vec![
+            (1, check.measure(1).unwrap()),
+            (3, check.measure(3).unwrap())
+        ]);
+        assert_eq!(removed[1].1, PauliStack::try_from_str("00", "10").unwrap());
+        assert_eq!(frames.frames_num(), 2);
+        let mut remaining =
+            frames.as_storage().iter_pairs().map(|(b, _)| b).collect::<Vec<_>>();
+        remaining.sort_unstable();
+        assert_eq!(remaining, vec![0, 2, 4]);
+        for bit in remaining {
+            assert_eq!(frames.get(bit), check.get(bit));
+        }
+        assert!(frames.remove_qubits(&[1, 3]).is_empty());
+    }
+
+    #[test]
+    fn measure_collapse() {
+        let mut frames = Frames::<BufferedVector<PauliStack<Vec<bool>>>>::init(3);
+        frames.track_x(0);
+        frames.cx(0, 1);
+        frames.track_y(1);
+        frames.track_z(2);
+        frames.cz(1, 2);
+        let check = frames.clone();
+
+        for outcome in [false, true] {
+            let mut frames = frames.clone();
+            let mut check = check.clone();
+            // BufferedVector only allows removing from the end
+            for bit in (0..3).rev() {
+                let expected =
+                    check
+                        .measure(bit)
+                        .unwrap()
+                        .sum_up(&vec![outcome; check.frames_num()]);
+                assert_eq!(frames.measure_collapse(bit, outcome), Ok(expected));
+            }
+            assert_eq!(frames, check);
+        }
+        assert_eq!(frames.measure_collapse(3, true), Err(MissingBit(3)));
+    }
+
+    #[test]
+    fn prepare() {
+        use crate::{pauli::PauliDense, tracker::live::Live};
+
+        let mut frames = Frames::<BufferedVector<PauliStack<Vec<bool>>>>::init(2);
+        let mut live = Live::<BufferedVector<PauliDense>>::init(2);
+        frames.track_x(0);
+        frames.track_z(1);
+        frames.cx(0, 1);
+        live.track_x(0);
+        live.track_z(1);
+        live.cx(0, 1);
+
+        frames.prepare_zero(1);
+        live.prepare_zero(1);
+        assert_eq!(frames.get(1), Some(&PauliStack::zeros(2)));
+        assert_eq!(live.get(1), Some(&PauliDense::I));
+        frames.prepare_plus(0);
+        live.prepare_plus(0);
+        assert_eq!(frames.get(0), Some(&PauliStack::zeros(2)));
+        assert_eq!(live.get(0), Some(&PauliDense::I));
+        assert_eq!(frames.frames_num(), 2);
+    }
+
+    #[test]
+    fn apply_to_all() {
+        use crate::collection::Map;
+
+        const NUM: usize = 4;
+        let mut frames = Frames::<Map<PauliStack<Vec<bool>>>>::init(NUM);
+        for i in 0..7 {
+            frames.track_y(i % NUM);
+            frames.cx(i % NUM, (i + 1) % NUM);
+        }
+        let mut check = frames.clone();
+        frames.apply_to_all(PauliStack::h);
+        for bit in 0..NUM {
+            check.h(bit);
+        }
+        assert_eq!(frames, check);
+        frames.apply_to_all(PauliStack::s);
+        for bit in 0..NUM {
+            check.s(bit);
+        }
+        assert_eq!(frames, check);
+    }
+
+    #[test]
+    fn gate_layers() {
+        type ThisFrames = Frames<BufferedVector<PauliStack<Vec<bool>>>>;
+
+        #[cfg_attr(coverage_nightly, coverage(off))]
+        fn check(
+            tracker: &ThisFrames,
+            layer: fn(&mut ThisFrames, &[(usize, usize)]),
+            gate: fn(&mut ThisFrames, usize, usize),
+            pairs: &[(usize, usize)],
+        ) {
+            let mut batched = tracker.clone();
+            let mut single = tracker.clone();
+            layer(&mut batched, pairs);
+            for &(bit_a, bit_b) in pairs {
+                gate(&mut single, bit_a, bit_b);
+            }
+            assert_eq!(batched, single, "{pairs:?}");
+            assert_ne!(batched, *tracker, "{pairs:?}");
+        }
+
+        let mut tracker = ThisFrames::init(6);
+        for i in 0..24 {
+            tracker.track_pauli(i % 6, PauliTuple::new_product(i % 5 < 2, i % 3 == 0));
+            tracker.h((5 * i) % 6);
+        }
+
+        // sharing qubits, including a duplicate edge
+        let edges = [(0, 1), (2, 3), (1, 2), (4, 0), (5, 1), (2, 1), (3, 0)];
+        check(&tracker, ThisFrames::cz_layer, ThisFrames::cz, &edges);
+        // batched, since no control is a target
+        let pairs = [(0, 1), (0, 2), (3, 2), (4, 1), (5, 1)];
+        check(&tracker, ThisFrames::cx_layer, ThisFrames::cx, &pairs);
+        // in order, since qubit 1 is a target and a control
+        let pairs = [(0, 1), (1, 2), (3, 1), (2, 4)];
+        check(&tracker, ThisFrames::cx_layer, ThisFrames::cx, &pairs);
+    }
+
+    #[test]
+    fn iter_frames() {
+        const NUM: usize = 5;
+        let mut frames = Frames::<BufferedVector<PauliStack<Vec<bool>>>>::init(NUM);
+        for i in 0..12 {
+            frames.track_y(i % NUM);
+            frames.cx(i % NUM, (i + 2) % NUM);
+            frames.h((3 * i) % NUM);
+        }
+        let transposed = frames.transpose::<PauliTuple>(NUM);
+        let iterated = frames
+            .iter_frames::<PauliTuple>()
+            .map(|frame| frame.into_iter().map(|(_, pauli)| pauli).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        assert_eq!(iterated, transposed);
+        assert_eq!(frames.iter_frames::<PauliTuple>().len(), 12);
+    }
+
+    #[test]
+    fn hardware_aliases() {
+        let mut v = Frames::<BufferedVector<PauliStack<Vec<bool>>>>::init(2);
+        v.track_x(0);
+        v.track_z(1);
+        v.track_y(0);
+        let mut sx = v.clone();
+        v.v(0);
+        v.vdg(1);
+        sx.sx(0);
+        sx.sxdg(1);
+        assert_eq!(v, sx);
+    }
+
+    #[test]
+    fn verify_against_live() {
+        use crate::{collection::Map, pauli::PauliDense};
+
+        const NUM: usize = 4;
+        let mut frames = Frames::<Map<PauliStack<Vec<bool>>>>::init(NUM);
+        let mut live = Live::<Map<PauliDense>>::init(NUM);
+        let outcomes = [true, false, true, true, false];
+        for (i, outcome) in outcomes.into_iter().enumerate() {
+            frames.track_y(i % NUM);
+            if outcome {
+                live.track_y(i % NUM);
+            }
+            frames.cx(i % NUM, (i + 1) % NUM);
+            live.cx(i % NUM, (i + 1) % NUM);
+            frames.h((i + 2) % NUM);
+            live.h((i + 2) % NUM);
+        }
+        assert_eq!(frames.verify_against_live(&live, &outcomes), Ok(()));
+
+        let mut wrong = live.clone();
+        wrong.track_z(2);
+        wrong.measure(3).unwrap();
+        assert_eq!(frames.verify_against_live(&wrong, &outcomes), Err(vec![2, 3]));
+    }
+
+    #[test]
+    fn native_gates() {
+        type T = Frames<BufferedVector<PauliStack<Vec<bool>>>>;
+        for gate in ["cz", "cx", "cy", "swap", "iswap"] {
+            assert!(T::NATIVE_GATES.contains(&gate), "{gate}");
+        }
+        assert!(!T::NATIVE_GATES.contains(&"sdg"));
+        assert_eq!(
+            <Live<BufferedVector<PauliTuple>> as Tracker>::NATIVE_GATES,
+            T::NATIVE_GATES
+        );
+    }
+
+    #[test]
+    fn frame_origins() {
+        use crate::collection::NaiveVector;
+
+        // the example from the documentation of the crate
+        let mut tracker =
+            FramesWithOrigins::<NaiveVector<PauliStack<Vec<bool>>>>::init(6);
+        tracker.track_pauli(4, 0, PauliTuple::X);
+        tracker.frames_mut().cx(0, 1);
+        tracker.frames_mut().s(1);
+        tracker.track_pauli(5, 2, PauliTuple::Y);
+        tracker.frames_mut().cz(1, 2);
+        tracker.frames_mut().cx(3, 2);
+        tracker.track_pauli(0, 1, PauliTuple::Z);
+        tracker.frames_mut().h(1);
+        tracker.frames_mut().cx(3, 1);
+        tracker.frames_mut().cz(3, 2);
+
+        let map = [4, 5, 0];
+        assert_eq!(tracker.frame_origins(), map);
+        assert_eq!(
+            tracker.induced_order(),
+            induced_order::get_order(tracker.frames().as_storage(), &map)
+        );
+        assert_eq!(tracker.induced_order(), vec![
+            vec![(3, vec![]), (4, vec![]), (5, vec![])],
+            vec![(0, vec![4]), (2, vec![4, 5])],
+            vec![(1, vec![5, 0])],
+        ]);
+
+        let (origin, _) = tracker.pop_frame::<PauliTuple>().unwrap();
+        assert_eq!(origin, 0);
+        assert_eq!(tracker.frame_origins(), [4, 5]);
+
+        let mut empty = FramesWithOrigins::<NaiveVector<PauliStack<Vec<bool>>>>::init(0);
+        empty.track_pauli(1, 0, PauliTuple::X);
+        assert!(empty.frame_origins().is_empty());
+    }
+
+    #[test]
+    fn total_memory_bytes() {
+        #[cfg_attr(coverage_nightly, coverage(off))]
+        fn check<B: BooleanVector>() {
+            let mut frames = Frames::<BufferedVector<PauliStack<B>>>::init(3);
+            let initial = frames.total_memory_bytes();
+            for i in 0..1000 {
+                frames.track_x(i % 3);
+            }
+            assert!(frames.total_memory_bytes() >= 3 * 2 * 1000 / 8);
+            assert!(frames.total_memory_bytes() > initial);
+        }
+        check::<Vec<bool>>();
+        check::<bitvec::vec::BitVec>();
+        check::<bit_vec::BitVec>();
+    }
+
+    #[test]
+    fn into_frames() {
+        use crate::collection::Map;
+
+        const NUM: usize = 5;
+        let mut frames = Frames::<Map<PauliStack<Vec<bool>>>>::init(NUM);
+        for i in 0..9 {
+            frames.track_y(i % NUM);
+            frames.cx(i % NUM, (i + 1 + i % 3) % NUM);
+            frames.h((3 * i) % NUM);
+        }
+        let transposed = frames.transpose::<PauliTuple>(NUM);
+        let consumed = frames.into_frames::<PauliTuple>(NUM);
+        assert_eq!(consumed.len(), 9);
+        assert_eq!(consumed.collect::<Vec<_>>(), transposed);
+    }
+
+    #[test]
+    fn for_each_frame() {
+        const NUM: usize = 4;
+        let mut frames = Frames::<BufferedVector<PauliStack<Vec<bool>>>>::init(NUM);
+        for i in 0..7 {
+            frames.track_y(i % NUM);
+            frames.cx(i % NUM, (i + 2) % NUM);
+            frames.sh((i + 1) % NUM);
+        }
+        let mut accumulated = Vec::new();
+        frames.for_each_frame(|idx, frame| {
+            assert_eq!(idx, accumulated.len());
+            let mut dense = vec![PauliTuple::I; NUM];
+            for (bit, pauli) in frame {
+                dense[*bit] = *pauli;
+            }
+            accumulated.push(dense);
+        });
+        assert_eq!(accumulated, frames.transpose::<PauliTuple>(NUM));
+    }
+
+    #[test]
+    fn filter_frames() {
+        const NUM: usize = 4;
+        let mut frames = Frames::<BufferedVector<PauliStack<Vec<bool>>>>::init(NUM);
+        for i in 0..9 {
+            frames.track_y(i % NUM);
+            frames.cx(i % NUM, (i + 2) % NUM);
+            frames.sh((i + 1) % NUM);
+        }
+        let has_support_on_0 = |frame: &PauliString<PauliTuple>| {
+            frame.iter().any(|(bit, pauli)| *bit == 0 && *pauli != PauliTuple::I)
+        };
+        let expected = frames
+            .iter_frames::<PauliTuple>()
+            .filter(has_support_on_0)
+            .collect::<Vec<_>>();
+        assert!(!expected.is_empty() && expected.len() < frames.frames_num());
+
+        frames.filter_frames(has_support_on_0);
+        assert_eq!(frames.frames_num(), expected.len());
+        assert_eq!(frames.iter_frames::<PauliTuple>().collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    fn weight_per_frame() {
+        // the example from induced_order::get_order
+        let frames = Frames::new_unchecked(
+            BufferedVector::from(vec![
+                PauliStack::<Vec<bool>>::try_from_str("00", "00").unwrap(),
+                PauliStack::<Vec<bool>>::try_from_str("10", "00").unwrap(),
+                PauliStack::<Vec<bool>>::try_from_str("01", "10").unwrap(),
+                PauliStack::<Vec<bool>>::try_from_str("10", "00").unwrap(),
+            ]),
+            2,
+        );
+        assert_eq!(frames.weight_per_frame(), vec![3, 1]);
+        assert_eq!(
+            Frames::<BufferedVector<PauliStack<Vec<bool>>>>::init(2).weight_per_frame(),
+            Vec::<usize>::new()
+        );
+    }
+
+    #[test]
+    fn total_weight() {
+        // the example from induced_order::get_order
+        let frames = Frames::new_unchecked(
+            BufferedVector::from(vec![
+                PauliStack::<Vec<bool>>::try_from_str("00", "00").unwrap(),
+                PauliStack::<Vec<bool>>::try_from_str("10", "00").unwrap(),
+                PauliStack::<Vec<bool>>::try_from_str("01", "10").unwrap(),
+                PauliStack::<Vec<bool>>::try_from_str("10", "00").unwrap(),
+            ]),
+            2,
+        );
+        assert_eq!(frames.total_weight(), 4);
+        assert_eq!(
+            frames.total_weight(),
+            frames.weight_per_frame().iter().sum::<usize>()
+        );
+        let transposed = frames.transpose::<PauliTuple>(4);
+        assert_eq!(
+            frames.total_weight(),
+            transposed
+                .iter()
+                .flatten()
+                .filter(|pauli| **pauli != PauliTuple::I)
+                .count()
+        );
+        assert_eq!(
+            Frames::<BufferedVector<PauliStack<Vec<bool>>>>::init(2).total_weight(),
+            0
+        );
+    }
+
+    #[test]
+    fn to_coo() {
+        // the example from induced_order::get_order, with full stacks
+        let frames = Frames::new_unchecked(
+            BufferedVector::from(vec![
+                PauliStack::<Vec<bool>>::try_from_str("00", "00").unwrap(),
+                PauliStack::<Vec<bool>>::try_from_str("10", "00").unwrap(),
+                PauliStack::<Vec<bool>>::try_from_str("01", "10").unwrap(),
+                PauliStack::<Vec<bool>>::try_from_str("10", "00").unwrap(),
+            ]),
+            2,
+        );
+        assert_eq!(
+            frames.to_coo(),
+            (vec![1, 2, 2, 3], vec![0, 0, 1, 0], vec![1, 2, 1, 1])
+        );
+    }
+
+    #[test]
+    fn frame_commutation_matrix() {
+        // frame 0: X0 Z1, frame 1: Z0, frame 2: Y0 X1
+        let frames = Frames::new_unchecked(
+            BufferedVector::from(vec![
+                PauliStack::<Vec<bool>>::try_from_str("011", "101").unwrap(),
+                PauliStack::<Vec<bool>>::try_from_str("100", "001").unwrap(),
+                PauliStack::<Vec<bool>>::try_from_str("000", "000").unwrap(),
+            ]),
+            3,
+        );
+        assert_eq!(frames.frame_commutation_matrix(), vec![
+            vec![false, true, false],
+            vec![true, false, true],
+            vec![false, true, false],
+        ]);
+        assert!(
+            Frames::<BufferedVector<PauliStack<Vec<bool>>>>::init(2)
+                .frame_commutation_matrix()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn apply_outcomes() {
+        use crate::{collection::Map, pauli::PauliDense};
+
+        let mut frames = Frames::<Map<PauliStack<Vec<bool>>>>::init(3);
+        frames.track_x(0); // 0: XII -> XXI
+        frames.track_z(1); // 1: IZI -> ZZI
+        frames.cx(0, 1);
+        frames.track_y(2); // 2: IIY
+        frames.track_x(1); // 3: IXI
+        frames.h(2); // the Y stays a Y
+        frames.track_z(2); // 4: IIZ
+
+        let live: Live<BufferedVector<PauliDense>> =
+            frames.apply_outcomes(&[Some(true), None, Some(true), Some(false), None]);
+
+        assert_eq!(frames.frames_num(), 2);
+        assert_eq!(frames.get(0), Some(&PauliStack::try_from_str("10", "00").unwrap()));
+        assert_eq!(frames.get(1), Some(&PauliStack::try_from_str("10", "00").unwrap()));
+        assert_eq!(frames.get(2), Some(&PauliStack::try_from_str("01", "00").unwrap()));
+        assert_eq!(live.into_storage().0, vec![
+            PauliDense::new_x(),
+            PauliDense::new_x(),
+            PauliDense::new_y()
+        ]);
+    }
+
+    #[test]
+    fn try_gates() {
+        use crate::{pauli::PauliDense, tracker::QubitError};
+
+        let mut frames = Frames::<BufferedVector<PauliStack<Vec<bool>>>>::init(2);
+        frames.track_x(0);
+        assert_eq!(frames.try_cx(0, 2), Err(MissingBit(2).into()));
+        assert_eq!(frames.try_h(3), Err(MissingBit(3)));
+        assert_eq!(frames.try_cz(1, 1), Err(QubitError::SameQubit(1)));
+        assert_eq!(frames.try_cx(0, 1), Ok(()));
+        assert_eq!(frames.get(1).unwrap().x, vec![true]);
+
+        // the methods are provided by the trait, so they are available in generic code
+        #[cfg_attr(coverage_nightly, coverage(off))]
+        fn generic<T: Tracker>(tracker: &mut T) -> Result<(), QubitError> {
+            tracker.try_h(0)?;
+            tracker.try_swap(0, 1)
+        }
+        let mut live = Live::<BufferedVector<PauliDense>>::init(2);
+        live.track_x(0);
+        assert_eq!(generic(&mut live), Ok(()));
+        assert_eq!(live.get(1), Some(&PauliDense::new_z()));
+        assert_eq!(
+            generic(&mut Live::<BufferedVector<PauliDense>>::init(1)),
+            Err(QubitError::MissingBit(MissingBit(1)))
+        );
+    }
+
+    #[test]
+    fn push_frame_str() {
+        use crate::collection::Map;
+
+        let mut frames = Frames::<Map<PauliStack<Vec<bool>>>>::init(4);
+        frames.track_x(2);
+        frames.push_frame_str("ZIYX").unwrap();
+        assert_eq!(frames.frames_num(), 2);
+        let mut frame = frames.get_frame::<PauliTuple>(1).unwrap();
+        frame.sort_by_key(|(bit, _)| *bit);
+        assert_eq!(frame, vec![
+            (0, PauliTuple::Z),
+            (1, PauliTuple::I),
+            (2, PauliTuple::Y),
+            (3, PauliTuple::X)
+        ]);
+
+        let check = frames.clone();
+        assert_eq!(
+            frames.push_frame_str("ZIY"),
+            Err(FrameShapeError::Length { expected: 4, found: 3 })
+        );
+        assert_eq!(
+            frames.push_frame_str("ZIAX"),
+            Err(FrameShapeError::Pauli { position: 2, chr: 'A' })
+        );
+        frames.measure(1).unwrap();
+        frames.new_qubit(4);
+        assert_eq!(frames.push_frame_str("ZIYX"), Err(MissingBit(1).into()));
+        frames.measure(4).unwrap();
+        frames.new_qubit(1);
+        assert_eq!(frames.frames_num(), check.frames_num());
+        assert_eq!(frames.get(0), check.get(0));
+    }
+
+    #[cfg(feature = "stim")]
+    #[test]
+    fn stim_strings() {
+        let mut frames = Frames::<BufferedVector<PauliStack<Vec<bool>>>>::init(5);
+        for i in 0..20 {
+            match i % 3 {
+                0 => frames.track_x(i % 5),
+                1 => frames.track_z((3 * i) % 5),
+                _ => frames.track_y((2 * i) % 5),
+            }
+            frames.cx(i % 5, (i + 2) % 5);
+            frames.sh((i + 1) % 5);
+        }
+
+        let strings = frames.to_stim_strings(5);
+        assert_eq!(strings.len(), 20);
+        let mut parsed = Frames::<BufferedVector<PauliStack<Vec<bool>>>>::init(5);
+        for string in strings.iter() {
+            assert!(string.starts_with('+'));
+            parsed.push_frame_stim_str(string).unwrap();
+        }
+        assert_eq!(parsed, frames);
+
+        assert_eq!(
+            parsed.push_frame_stim_str("+X_Y"),
+            Err(FrameShapeError::Length { expected: 5, found: 3 })
+        );
+        assert_eq!(
+            parsed.push_frame_stim_str("-X_YAZ"),
+            Err(FrameShapeError::Pauli { position: 3, chr: 'A' })
+        );
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_transpose() {
+        use crate::collection::Map;
+
+        let mut frames = Frames::<Map<PauliStack<Vec<bool>>>>::init(7);
+        for i in 0..50 {
+            match i % 3 {
+                0 => frames.track_x(i % 7),
+                1 => frames.track_z((3 * i) % 7),
+                _ => frames.track_y((5 * i) % 7),
+            }
+            frames.cx(i % 7, (i + 2) % 7);
+            frames.h((2 * i) % 7);
+            frames.cz((i + 1) % 7, (i + 5) % 7);
+        }
+        frames.new_qubit(9);
+        assert_eq!(
+            frames.par_transpose::<PauliTuple>(10),
+            frames.transpose::<PauliTuple>(10)
+        );
+    }
 }
@@ -1,9 +1,10 @@
 /*!
 The main content of this module is the [get_order] function that can be
-used to define a time ordering induced by the tracked frames.
+used to define a time ordering induced by the tracked frames. The [IncrementalOrder]
+builds the same ordering incrementally, while the qubits are measured.
 */
 
-use hashbrown::HashSet;
+use hashbrown::{HashMap, HashSet};
 
 use crate::{boolean_vector::BooleanVector, pauli::PauliStack};
 
@@ -82,7 +83,7 @@ where
                 deps.insert(map[dep]);
             }
         }
-        let deps = Vec::from_iter(deps.into_iter());
+        let deps = Vec::from_iter(deps);
 
         if deps.is_empty() {
             graph[0].push((bit, deps));
@@ -145,6 +146,108 @@ where
     graph
 }
 
+/// A builder for the [PartialOrderGraph] that adds the qubits one by one, e.g., directly
+/// when they are measured, instead of processing the whole storage at once as in
+/// [get_order].
+///
+/// The qubits have to be [insert](Self::insert)ed in a valid time order, i.e., after
+/// all the qubits they depend on; this is naturally the case when inserting them when
+/// they are measured. Up to the order of the nodes within the layers and the order of
+/// the dependencies, the [finish](Self::finish)ed graph is the same as the graph
+/// returned by [get_order] on the same stacks. Compare also
+/// [TrackedCircuit::measure_and_record](crate::circuit::TrackedCircuit::measure_and_record).
+///
+/// # Examples
+/// ```
+/// # fn main() { #![cfg_attr(coverage_nightly, coverage(off))]
+/// use pauli_tracker::{
+///     pauli::PauliStack, tracker::frames::induced_order::IncrementalOrder,
+/// };
+/// // the example from get_order
+/// let mut order = IncrementalOrder::new(vec![0, 3]);
+/// order.insert(0, &PauliStack::<Vec<bool>>::try_from_str("", "").unwrap());
+/// order.insert(3, &PauliStack::<Vec<bool>>::try_from_str("1", "0").unwrap());
+/// order.insert(1, &PauliStack::<Vec<bool>>::try_from_str("10", "00").unwrap());
+/// order.insert(2, &PauliStack::<Vec<bool>>::try_from_str("01", "10").unwrap());
+/// assert_eq!(order.finish(), vec![
+///     vec![(0, vec![])],
+///     vec![(3, vec![0]), (1, vec![0])],
+///     vec![(2, vec![3])],
+/// ]);
+/// # }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IncrementalOrder {
+    map: Vec<usize>,
+    graph: PartialOrderGraph,
+    // qubit -> (layer, position in the layer)
+    known: HashMap<usize, (usize, usize)>,
+}
+
+impl IncrementalOrder {
+    /// Create a new, empty [IncrementalOrder], where `map` maps the frames to the qubits
+    /// that induced them, as in [get_order].
+    pub fn new(map: Vec<usize>) -> Self {
+        Self { map, ..Default::default() }
+    }
+
+    /// Append the qubit that induced the next frame to the `map`, e.g., when a new
+    /// frame is tracked after the builder has been created.
+    pub fn push_frame_origin(&mut self, bit: usize) {
+        self.map.push(bit);
+    }
+
+    /// Reference the graph built so far.
+    pub fn graph(&self) -> &PartialOrderGraph {
+        &self.graph
+    }
+
+    /// Finish the building and return the graph.
+    pub fn finish(self) -> PartialOrderGraph {
+        self.graph
+    }
+
+    /// Add qu`bit` with its Pauli `stack` to the graph.
+    ///
+    /// # Panics
+    /// Panics if the `map` is too short for the `stack`, if qu`bit` has already been
+    /// inserted, or if qu`bit` depends on a qubit that has not been inserted yet.
+    pub fn insert<B: BooleanVector>(&mut self, bit: usize, stack: &PauliStack<B>) {
+        assert!(!self.known.contains_key(&bit), "qubit {bit} is already in the graph");
+
+        let max = stack.z.len().max(stack.x.len());
+        let mut z = stack.z.clone();
+        z.resize(max, false);
+        let mut x = stack.x.clone();
+        x.resize(max, false);
+        z.or_inplace(&x);
+
+        let mut deps: HashSet<usize> = HashSet::new();
+        for (frame, flag) in z.iter_vals().enumerate() {
+            if flag {
+                deps.insert(self.map[frame]);
+            }
+        }
+
+        let mut layer = 0;
+        let mut redundant = HashSet::new();
+        for dep in deps.iter() {
+            let (dep_layer, position) = *self.known.get(dep).unwrap_or_else(|| {
+                panic!("qubit {bit} depends on qubit {dep}, which is not in the graph")
+            });
+            layer = layer.max(dep_layer + 1);
+            redundant.extend(self.graph[dep_layer][position].1.iter().copied());
+        }
+        let deps = deps.into_iter().filter(|dep| !redundant.contains(dep)).collect();
+
+        if self.graph.len() <= layer {
+            self.graph.resize_with(layer + 1, Vec::new);
+        }
+        self.known.insert(bit, (layer, self.graph[layer].len()));
+        self.graph[layer].push((bit, deps));
+    }
+}
+
 /// Sort the nodes in a layer of the `graph` according to their qubit number.
 ///
 /// # Examples
@@ -163,11 +266,146 @@ pub fn sort_layers_by_bits(graph: &mut PartialOrderGraph) {
     }
 }
 
+/// Flatten the `graph` into one valid serial (time) order of the qubits, i.e., every
+/// qubit comes after all of its dependencies. The order goes layer by layer, with the
+/// qubits within a layer sorted by their qubit number.
+///
+/// # Examples
+/// ```
+/// # fn main() { #![cfg_attr(coverage_nightly, coverage(off))]
+/// # use pauli_tracker::tracker::frames::induced_order::flatten;
+/// let graph =
+///     vec![vec![(0, vec![])], vec![(3, vec![0]), (1, vec![0])], vec![(2, vec![3])]];
+/// assert_eq!(flatten(&graph), vec![0, 1, 3, 2]);
+/// # }
+/// ```
+pub fn flatten(graph: &PartialOrderGraph) -> Vec<usize> {
+    let mut order = Vec::with_capacity(graph.iter().map(Vec::len).sum());
+    for layer in graph {
+        let start = order.len();
+        order.extend(layer.iter().map(|(bit, _)| *bit));
+        order[start..].sort_unstable();
+    }
+    order
+}
+
+/// Some basic metrics of a [PartialOrderGraph], cf. [metrics].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct GraphMetrics {
+    /// The number of layers, i.e., the length of the critical path, or the number of
+    /// sequential measurement steps.
+    pub depth: usize,
+    /// The maximum number of qubits in a layer, i.e., the maximum number of qubits that
+    /// are measured in parallel.
+    pub max_width: usize,
+    /// The total number of qubits in the graph.
+    pub total_qubits: usize,
+}
+
+/// Get the [GraphMetrics] of the `graph`, e.g., to estimate the required size of a
+/// quantum processor.
+///
+/// # Examples
+/// ```
+/// # fn main() { #![cfg_attr(coverage_nightly, coverage(off))]
+/// # use pauli_tracker::tracker::frames::induced_order::{metrics, GraphMetrics};
+/// let graph =
+///     vec![vec![(0, vec![])], vec![(3, vec![0]), (1, vec![0])], vec![(2, vec![3])]];
+/// assert_eq!(metrics(&graph), GraphMetrics {
+///     depth: 3,
+///     max_width: 2,
+///     total_qubits: 4
+/// });
+/// # }
+/// ```
+pub fn metrics(graph: &PartialOrderGraph) -> GraphMetrics {
+    GraphMetrics {
+        depth: graph.len(),
+        max_width: graph.iter().map(Vec::len).max().unwrap_or(0),
+        total_qubits: graph.iter().map(Vec::len).sum(),
+    }
+}
+
+/// The difference between two [PartialOrderGraph]s, cf. [diff].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GraphDiff {
+    /// The qubits that are in different layers, as (qubit, layer in `a`, layer in `b`),
+    /// where the layer is [None] if the qubit is not in the according graph; sorted by
+    /// the qubits.
+    pub moved: Vec<(usize, Option<usize>, Option<usize>)>,
+    /// The dependencies (qubit, dependency) that are in `b` but not in `a`; sorted.
+    pub added: Vec<(usize, usize)>,
+    /// The dependencies (qubit, dependency) that are in `a` but not in `b`; sorted.
+    pub removed: Vec<(usize, usize)>,
+}
+
+impl GraphDiff {
+    /// Check whether the two graphs are equivalent, i.e., whether they only differ in the
+    /// order of the qubits within the layers or the order of the dependencies.
+    pub fn is_empty(&self) -> bool {
+        self.moved.is_empty() && self.added.is_empty() && self.removed.is_empty()
+    }
+}
+
+/// Compare the two graphs `a` and `b`, reporting which qubits moved into other layers
+/// and which dependencies were added or removed, e.g., to see how the ordering changes
+/// when the circuit is slightly modified.
+///
+/// # Examples
+/// ```
+/// # fn main() { #![cfg_attr(coverage_nightly, coverage(off))]
+/// # use pauli_tracker::tracker::frames::induced_order::{diff, GraphDiff};
+/// let a = vec![vec![(0, vec![])], vec![(3, vec![0]), (1, vec![0])], vec![(2, vec![3])]];
+/// // 1 depends now on 3 instead of 0 (the dependency on 0 is transitive now)
+/// let b = vec![vec![(0, vec![])], vec![(3, vec![0])], vec![(2, vec![3]), (1, vec![3])]];
+/// assert_eq!(diff(&a, &b), GraphDiff {
+///     moved: vec![(1, Some(1), Some(2))],
+///     added: vec![(1, 3)],
+///     removed: vec![(1, 0)],
+/// });
+/// # }
+/// ```
+pub fn diff(a: &PartialOrderGraph, b: &PartialOrderGraph) -> GraphDiff {
+    fn layers(graph: &PartialOrderGraph) -> HashMap<usize, usize> {
+        graph
+            .iter()
+            .enumerate()
+            .flat_map(|(l, layer)| layer.iter().map(move |(bit, _)| (*bit, l)))
+            .collect()
+    }
+    fn edges(graph: &PartialOrderGraph) -> HashSet<(usize, usize)> {
+        graph
+            .iter()
+            .flatten()
+            .flat_map(|(bit, deps)| deps.iter().map(move |dep| (*bit, *dep)))
+            .collect()
+    }
+
+    let (a_layers, b_layers) = (layers(a), layers(b));
+    let mut moved = a_layers
+        .keys()
+        .chain(b_layers.keys().filter(|bit| !a_layers.contains_key(*bit)))
+        .filter_map(|bit| {
+            let (in_a, in_b) = (a_layers.get(bit).copied(), b_layers.get(bit).copied());
+            (in_a != in_b).then_some((*bit, in_a, in_b))
+        })
+        .collect::<Vec<_>>();
+    moved.sort_unstable();
+
+    let (a_edges, b_edges) = (edges(a), edges(b));
+    let mut added = b_edges.difference(&a_edges).copied().collect::<Vec<_>>();
+    added.sort_unstable();
+    let mut removed = a_edges.difference(&b_edges).copied().collect::<Vec<_>>();
+    removed.sort_unstable();
+
+    GraphDiff { moved, added, removed }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::{
-        collection::{Init, Iterable, Map},
+        collection::{BufferedVector, Init, Iterable, Map},
         tracker::{Tracker, frames::Frames},
     };
 
@@ -179,4 +417,78 @@ mod tests {
         tracker.track_x(1);
         let _graph = get_order(tracker.as_storage().iter_pairs(), &map);
     }
+
+    #[test]
+    fn flatten_respects_dependencies() {
+        let storage = BufferedVector::from(vec![
+            PauliStack::<Vec<bool>>::try_from_str("000", "000").unwrap(),
+            PauliStack::<Vec<bool>>::try_from_str("100", "000").unwrap(),
+            PauliStack::<Vec<bool>>::try_from_str("010", "001").unwrap(),
+            PauliStack::<Vec<bool>>::try_from_str("000", "100").unwrap(),
+            PauliStack::<Vec<bool>>::try_from_str("010", "010").unwrap(),
+            PauliStack::<Vec<bool>>::try_from_str("000", "000").unwrap(),
+        ]);
+        let map = [0, 3, 1];
+        let graph = get_order(storage.iter_pairs(), &map);
+
+        let order = flatten(&graph);
+        assert_eq!(order.len(), 6);
+        let position = |bit: usize| order.iter().position(|&b| b == bit).unwrap();
+        for layer in graph {
+            for (bit, deps) in layer {
+                for dep in deps {
+                    assert!(position(dep) < position(bit), "{dep} !< {bit}: {order:?}");
+                }
+            }
+        }
+    }
+    #[test]
+    fn metrics() {
+        // the graph from the "MBQC" example in the crate documentation
+        let graph = vec![
+            vec![(3, vec![]), (4, vec![]), (5, vec![])],
+            vec![(0, vec![4]), (2, vec![4, 5])],
+            vec![(1, vec![5, 0])],
+        ];
+        assert_eq!(super::metrics(&graph), GraphMetrics {
+            depth: 3,
+            max_width: 3,
+            total_qubits: 6,
+        });
+        assert_eq!(super::metrics(&Vec::new()), GraphMetrics::default());
+    }
+
+    #[test]
+    fn graph_diff() {
+        let graph =
+            vec![vec![(0, vec![])], vec![(3, vec![0]), (1, vec![0])], vec![(2, vec![3])]];
+        assert!(diff(&graph, &graph).is_empty());
+
+        // the order within the layers does not matter
+        let mut sorted = graph.clone();
+        sort_layers_by_bits(&mut sorted);
+        assert!(diff(&graph, &sorted).is_empty());
+
+        // one extra edge, without changing the layers
+        let mut modified = graph.clone();
+        modified[2][0].1.push(1);
+        assert_eq!(diff(&graph, &modified), GraphDiff {
+            moved: vec![],
+            added: vec![(2, 1)],
+            removed: vec![],
+        });
+        assert_eq!(diff(&modified, &graph), GraphDiff {
+            moved: vec![],
+            added: vec![],
+            removed: vec![(2, 1)],
+        });
+
+        // a new qubit
+        modified.push(vec![(4, vec![2])]);
+        assert_eq!(diff(&graph, &modified), GraphDiff {
+            moved: vec![(4, None, Some(3))],
+            added: vec![(2, 1), (4, 2)],
+            removed: vec![],
+        });
+    }
 }
@@ -0,0 +1,249 @@
+/*!
+Track only the X or only the Z part of Pauli frames.
+
+This module provides the [HalfFrames] tracker. It is similar to the [Frames] tracker,
+but it stores only one of the two parts of the [PauliStack]s, i.e., one [BooleanVector]
+per qubit, which halves the memory. This is useful when one is only interested in the
+X part (or the Z part) of the tracked Paulis, for example, in some stabilizer analyses.
+
+The catch is that only gates that do not mix the X and Z parts into the tracked part
+can be supported. For the X part, these are the S, CZ, CX and SWAP gates (and
+everything that they generate), and for the Z part these are only the CX and SWAP
+gates. Compare the [conjugation-rules]. Gates that would mix the parts
+return a [MixingGate] error.
+
+[Frames]: super::frames::Frames
+[PauliStack]: crate::pauli::PauliStack
+[conjugation-rules]:
+https://github.com/taeruh/pauli_tracker/blob/main/docs/conjugation_rules.pdf
+*/
+
+use std::mem;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use super::MissingBit;
+use crate::{
+    boolean_vector::BooleanVector,
+    collection::{Init, IterableBase},
+};
+
+/// The part of the Paulis that is tracked by [HalfFrames].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Half {
+    /// Track the X part, i.e., the bits flagging whether there's a X Pauli.
+    #[default]
+    X,
+    /// Track the Z part, i.e., the bits flagging whether there's a Z Pauli.
+    Z,
+}
+
+/// The Error when one applies a gate that would mix the untracked part of the Paulis
+/// into the tracked part.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Error)]
+#[error("the {gate} gate mixes the X and Z parts, but only the {half:?} part is tracked")]
+pub struct MixingGate {
+    /// The name of the gate.
+    pub gate: &'static str,
+    /// The tracked part.
+    pub half: Half,
+}
+
+/// A container of multiple Pauli frames, storing only the X or only the Z part of the
+/// frames.
+///
+/// Compare the [module documentation](super::half_frames). To be useful, the generic
+/// `Storage` type should implement [IterableBase], with [BooleanVector]s as elements.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct HalfFrames<Storage> {
+    storage: Storage,
+    frames_num: usize,
+    half: Half,
+}
+
+impl<S> HalfFrames<S> {
+    /// Create a new [HalfFrames] instance, tracking the `half` part of the Paulis, with
+    /// a given storage and number of frames.
+    ///
+    /// It does not check whether the storage is compatible with the number of frames,
+    /// compare [Frames::new_unchecked](super::frames::Frames::new_unchecked).
+    pub fn new_unchecked(storage: S, frames_num: usize, half: Half) -> Self {
+        Self { storage, frames_num, half }
+    }
+
+    /// Reference the underlining storage.
+    pub fn as_storage(&self) -> &S {
+        &self.storage
+    }
+
+    /// Convert the object into the underlining storage.
+    pub fn into_storage(self) -> S {
+        self.storage
+    }
+
+    /// Get the number of tracked frames, i.e., the length of the vectors in the inner
+    /// storage.
+    pub fn frames_num(&self) -> usize {
+        self.frames_num
+    }
+
+    /// Get the tracked part of the Paulis.
+    pub fn half(&self) -> Half {
+        self.half
+    }
+}
+
+impl<S: Init> HalfFrames<S> {
+    /// Create a new [HalfFrames] instance with `len` qubits, tracking the `half` part of
+    /// the Paulis.
+    pub fn init(len: usize, half: Half) -> Self {
+        Self {
+            storage: S::init(len),
+            frames_num: 0,
+            half,
+        }
+    }
+}
+
+impl<S, B> HalfFrames<S>
+where
+    S: IterableBase<T = B>,
+    B: BooleanVector,
+{
+    /// Returns a reference to `bit`s tracked part; [None] if `bit` is not present.
+    pub fn get(&self, bit: usize) -> Option<&B> {
+        self.storage.get(bit)
+    }
+
+    /// Insert a new qu`bit` into the tracker. If the qu`bit` is already present, the old
+    /// value is overwritten and returned.
+    pub fn new_qubit(&mut self, bit: usize) -> Option<B> {
+        self.storage.insert(bit, B::zeros(self.frames_num))
+    }
+
+    /// Track a new frame consisting of a Pauli at qu`bit` whose tracked part is set,
+    /// e.g., a X (or Y) Pauli if the X part is tracked.
+    ///
+    /// If qu`bit` is not tracked, the method does not error, but simply tracks an empty
+    /// frame.
+    pub fn track(&mut self, bit: usize) {
+        if self.storage.is_empty() {
+            return;
+        }
+        for (i, b) in self.storage.iter_pairs_mut() {
+            b.push(i == bit);
+        }
+        self.frames_num += 1;
+    }
+
+    /// Update the tracked frames according to the S gate on the qu`bit`. Errors if the Z
+    /// part is tracked.
+    ///
+    /// # Panics
+    /// Panics if qu`bit` does not exist, as for the other gates, even though the X part
+    /// is not changed.
+    pub fn s(&mut self, bit: usize) -> Result<(), MixingGate> {
+        unwrap_get_mut!(self.storage, bit, "s");
+        match self.half {
+            // the S gate only changes the Z part
+            Half::X => Ok(()),
+            Half::Z => Err(MixingGate { gate: "S", half: self.half }),
+        }
+    }
+
+    /// Update the tracked frames according to the Control Z gate on the `bit_a` and
+    /// `bit_b` qubits. Errors if the Z part is tracked.
+    ///
+    /// # Panics
+    /// Panics if one of the qubits does not exist or if they are the same, as for the
+    /// other gates, even though the X part is not changed.
+    pub fn cz(&mut self, bit_a: usize, bit_b: usize) -> Result<(), MixingGate> {
+        unwrap_get_two_mut!(self.storage, bit_a, bit_b, "cz");
+        match self.half {
+            // the CZ gate only changes the Z part
+            Half::X => Ok(()),
+            Half::Z => Err(MixingGate { gate: "CZ", half: self.half }),
+        }
+    }
+
+    /// Update the tracked frames according to the Control X (Control Not) gate on the
+    /// `control` and `target` qubits.
+    pub fn cx(&mut self, control: usize, target: usize) {
+        let (c, t) = unwrap_get_two_mut!(self.storage, control, target, "cx");
+        match self.half {
+            Half::X => t.xor_inplace(c),
+            Half::Z => c.xor_inplace(t),
+        }
+    }
+
+    /// Update the tracked frames according to the Swap gate on the `bit_a` and `bit_b`
+    /// qubits.
+    pub fn swap(&mut self, bit_a: usize, bit_b: usize) {
+        let (a, b) = unwrap_get_two_mut!(self.storage, bit_a, bit_b, "swap");
+        mem::swap(a, b)
+    }
+
+    /// Remove the tracked part on qu`bit`, if it is present.
+    pub fn measure(&mut self, bit: usize) -> Result<B, MissingBit> {
+        self.storage.remove(bit).ok_or(MissingBit(bit))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use coverage_helper::test;
+
+    use super::*;
+    use crate::{
+        collection::Map,
+        pauli::PauliStack,
+        tracker::{Tracker, frames::Frames},
+    };
+
+    #[test]
+    fn conformance() {
+        const NUM: usize = 6;
+        for half in [Half::X, Half::Z] {
+            let mut frames = Frames::<Map<PauliStack<Vec<bool>>>>::init(NUM);
+            let mut half_frames = HalfFrames::<Map<Vec<bool>>>::init(NUM, half);
+            for i in 0..30 {
+                let (a, b) = (i % NUM, (3 * i + 1) % NUM);
+                match half {
+                    Half::X => frames.track_x(a),
+                    Half::Z => frames.track_z(a),
+                }
+                half_frames.track(a);
+                frames.cx(a, b);
+                half_frames.cx(a, b);
+                if half == Half::X {
+                    frames.s(b);
+                    half_frames.s(b).unwrap();
+                    frames.cz((a + 2) % NUM, (b + 4) % NUM);
+                    half_frames.cz((a + 2) % NUM, (b + 4) % NUM).unwrap();
+                }
+                if i % 4 == 0 {
+                    frames.swap(a, (a + 1) % NUM);
+                    half_frames.swap(a, (a + 1) % NUM);
+                }
+            }
+            assert_eq!(half_frames.frames_num(), frames.frames_num());
+            for bit in 0..NUM {
+                let stack = frames.get(bit).unwrap();
+                let expected = match half {
+                    Half::X => &stack.x,
+                    Half::Z => &stack.z,
+                };
+                assert_eq!(half_frames.get(bit), Some(expected), "{half:?}: {bit}");
+            }
+        }
+
+        let mut half_frames = HalfFrames::<Map<Vec<bool>>>::init(2, Half::Z);
+        assert_eq!(half_frames.s(0), Err(MixingGate { gate: "S", half: Half::Z }));
+        assert_eq!(half_frames.cz(0, 1), Err(MixingGate { gate: "CZ", half: Half::Z }));
+        assert_eq!(half_frames.measure(2), Err(MissingBit(2)));
+    }
+}
@@ -12,34 +12,65 @@ Paulis via `track_x/y/z` methods.
 [Frames]: super::frames::Frames
 */
 
-use std::mem;
+use std::{
+    fmt, mem,
+    ops::{Index, IndexMut},
+};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use super::{MissingBit, PauliString, Tracker};
+use super::{MissingBit, PauliString, Tracker, frames::Frames};
 use crate::{
-    collection::{Base, Init},
-    pauli::Pauli,
+    boolean_vector::BooleanVector,
+    collection::{Base, Init, Iterable},
+    pauli::{Pauli, PauliStack, PauliTuple},
 };
 
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub mod compact;
+
+mod history;
+pub use history::LiveWithHistory;
+
 // todo: make it generic and also do it with a hashmap
 
 /// An implementor of [Tracker] that tracks Pauli gates at runtime.
 ///
 /// Compare the [module documentation](super::live). To be useful, the generic `Storage`
 /// type should at least implement [Base], with implementors of [Pauli] as elements.
+///
+/// If the [Debug] representation of the storage is longer than 10000 characters, the
+/// [Debug] implementation elides it, e.g., `Live { storage: .., outcome_parity: false }`.
+/// Use the alternate flag, `{:#?}`, to print all the Paulis anyway.
+///
+/// Note that the comparison and hashing implementations also take the
+/// [outcome_parity](Self::outcome_parity) into account.
 // I'm not sure what the most efficient inner type would be here, Vec<bool>, Vec<Pauli>,
 // BitVec, ...
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Live<Storage> {
     storage: Storage,
+    // the default only applies to self-describing formats; binary formats like bincode
+    // require the field
+    #[cfg_attr(feature = "serde", serde(default))]
+    outcome_parity: bool,
+}
+
+impl<S: fmt::Debug> fmt::Debug for Live<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let alternate = f.alternate();
+        let mut debug = f.debug_struct("Live");
+        super::debug_storage(&mut debug, &self.storage, alternate);
+        debug.field("outcome_parity", &self.outcome_parity).finish()
+    }
 }
 
 impl<S> From<S> for Live<S> {
     fn from(value: S) -> Self {
-        Self { storage: value }
+        Self::new(value)
     }
 }
 
@@ -52,7 +83,7 @@ impl<S> AsRef<S> for Live<S> {
 impl<S> Live<S> {
     /// Creates a new [Live] tracker with the given storage.
     pub fn new(storage: S) -> Self {
-        Self { storage }
+        Self { storage, outcome_parity: false }
     }
 
     /// Convert the object into the underlining storage.
@@ -64,11 +95,17 @@ impl<S> Live<S> {
     pub fn as_storage(&self) -> &S {
         &self.storage
     }
+
+    /// Get the XOR parity of all outcomes that were passed to
+    /// [track_pauli_if](Self::track_pauli_if), e.g., for the bookkeeping of a decoder.
+    pub fn outcome_parity(&self) -> bool {
+        self.outcome_parity
+    }
 }
 
 impl<S: Init> Init for Live<S> {
     fn init(len: usize) -> Self {
-        Self { storage: S::init(len) }
+        Self::new(S::init(len))
     }
 }
 
@@ -85,6 +122,119 @@ where
     pub fn get_mut(&mut self, bit: usize) -> Option<&mut T> {
         self.storage.get_mut(bit)
     }
+
+    /// Returns `bit`s Pauli converted into another [Pauli] representation; [None] if
+    /// `bit` is not present.
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() { #![cfg_attr(coverage_nightly, coverage(off))]
+    /// # use pauli_tracker::{collection::{BufferedVector, Init}, pauli::{PauliDense,
+    /// #     PauliEnum}, tracker::{Tracker, live::Live}};
+    /// let mut tracker = Live::<BufferedVector<PauliDense>>::init(2);
+    /// tracker.track_x(0);
+    /// tracker.cx(0, 1);
+    /// match tracker.get_as::<PauliEnum>(1) {
+    ///     Some(PauliEnum::X) => {},
+    ///     _ => panic!("expected an X on qubit 1"),
+    /// }
+    /// assert_eq!(tracker.get_as::<PauliEnum>(2), None);
+    /// # }
+    /// ```
+    pub fn get_as<P: Pauli + From<T>>(&self, bit: usize) -> Option<P>
+    where
+        T: Clone,
+    {
+        self.storage.get(bit).cloned().map(P::from)
+    }
+}
+
+/// Index the tracker by the qubits, returning the according Pauli.
+///
+/// # Panics
+/// Panics if the qubit is not present; use [Live::get] or [Live::get_mut] for a
+/// non-panicking alternative.
+///
+/// # Examples
+/// ```
+/// # fn main() { #![cfg_attr(coverage_nightly, coverage(off))]
+/// # use pauli_tracker::{collection::{BufferedVector, Init}, pauli::{Pauli, PauliTuple},
+/// #     tracker::{Tracker, live::Live}};
+/// let mut tracker = Live::<BufferedVector<PauliTuple>>::init(2);
+/// tracker.track_x(0);
+/// tracker.cx(0, 1);
+/// assert_eq!(tracker[1], PauliTuple::X);
+/// tracker[1] = PauliTuple::Z;
+/// assert_eq!(tracker.get(1), Some(&PauliTuple::Z));
+/// # }
+/// ```
+impl<S, T> Index<usize> for Live<S>
+where
+    S: Base<TB = T>,
+{
+    type Output = T;
+    fn index(&self, bit: usize) -> &T {
+        self.get(bit)
+            .unwrap_or_else(|| panic!("there's no Pauli for qubit {bit}"))
+    }
+}
+
+impl<S, T> IndexMut<usize> for Live<S>
+where
+    S: Base<TB = T>,
+{
+    fn index_mut(&mut self, bit: usize) -> &mut T {
+        self.get_mut(bit)
+            .unwrap_or_else(|| panic!("there's no Pauli for qubit {bit}"))
+    }
+}
+
+impl<S, P> Live<S>
+where
+    S: Base<TB = P>,
+    P: Pauli,
+{
+    /// Collapse the `frames` with the measurement `outcomes` of the frames and multiply
+    /// the result into the tracked Paulis, i.e., for each qubit in `frames`, multiply in
+    /// the [sum_up](PauliStack::sum_up) of its stack. Qubits that are not yet in `self`
+    /// are inserted.
+    ///
+    /// # Panics
+    /// Panics if `outcomes.len()` < [frames_num](Frames::frames_num), cf.
+    /// [PauliStack::sum_up].
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() { #![cfg_attr(coverage_nightly, coverage(off))]
+    /// # use pauli_tracker::{collection::{BufferedVector, Init}, pauli::{Pauli,
+    /// #     PauliStack, PauliTuple}, tracker::{Tracker, frames::Frames, live::Live}};
+    /// let mut frames = Frames::<BufferedVector<PauliStack<Vec<bool>>>>::init(2);
+    /// frames.track_x(0);
+    /// frames.track_z(1);
+    /// frames.track_z(0);
+    /// let mut live = Live::<BufferedVector<PauliTuple>>::init(2);
+    /// live.track_x(1);
+    /// live.apply_frames(&frames, &[true, true, true]);
+    /// assert_eq!(live.get(0), Some(&PauliTuple::Y));
+    /// assert_eq!(live.get(1), Some(&PauliTuple::Y));
+    /// # }
+    /// ```
+    pub fn apply_frames<S2, B>(&mut self, frames: &Frames<S2>, outcomes: &[bool])
+    where
+        S2: Iterable<TI = PauliStack<B>>,
+        B: BooleanVector,
+    {
+        for (bit, stack) in frames.as_storage().iter_pairs() {
+            let correction = stack.sum_up(outcomes);
+            let correction = P::new_product(correction.get_z(), correction.get_x());
+            match self.storage.get_mut(bit) {
+                Some(pauli) => pauli.multiply(correction),
+                None => {
+                    self.storage.insert(bit, correction);
+                },
+            }
+        }
+    }
 }
 
 macro_rules! single {
@@ -114,6 +264,75 @@ macro_rules! remove {
     )*};
 }
 
+impl<S, P> Live<S>
+where
+    S: Base<TB = P>,
+    P: Pauli + Clone,
+{
+    /// Track the `pauli` on qu`bit` if the measurement `outcome` is true, and accumulate
+    /// the `outcome` into the [outcome_parity](Self::outcome_parity).
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() { #![cfg_attr(coverage_nightly, coverage(off))]
+    /// # use pauli_tracker::{collection::{BufferedVector, Init}, pauli::{Pauli,
+    /// #     PauliDense}, tracker::live::Live};
+    /// let mut live = Live::<BufferedVector<PauliDense>>::init(2);
+    /// live.track_pauli_if(0, PauliDense::X, true);
+    /// live.track_pauli_if(1, PauliDense::Z, false);
+    /// assert_eq!(live.get(0), Some(&PauliDense::X));
+    /// assert_eq!(live.get(1), Some(&PauliDense::I));
+    /// assert!(live.outcome_parity());
+    /// # }
+    /// ```
+    pub fn track_pauli_if(&mut self, bit: usize, pauli: P, outcome: bool) {
+        if outcome {
+            self.track_pauli(bit, pauli);
+        }
+        self.outcome_parity ^= outcome;
+    }
+
+    /// Track the Paulis of the `string` for which the corresponding entry in the `mask`
+    /// is true, i.e., the i-th Pauli is tracked if `mask`\[i\] is true, e.g., to apply
+    /// a correction frame whose parts are conditioned on different measurement
+    /// outcomes.
+    ///
+    /// In contrast to [track_pauli_if](Self::track_pauli_if), the
+    /// [outcome_parity](Self::outcome_parity) is not changed, since the `mask` entries
+    /// are not necessarily independent outcomes.
+    ///
+    /// # Panics
+    /// Panics if the `mask` and the `string` have different lengths.
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() { #![cfg_attr(coverage_nightly, coverage(off))]
+    /// # use pauli_tracker::{collection::{BufferedVector, Init}, pauli::{Pauli,
+    /// #     PauliDense, PauliTuple}, tracker::live::Live};
+    /// let mut live = Live::<BufferedVector<PauliDense>>::init(2);
+    /// live.track_pauli_string_masked(&vec![(0, PauliTuple::X), (1, PauliTuple::Z)], &[
+    ///     false, true,
+    /// ]);
+    /// assert_eq!(live.get(0), Some(&PauliDense::I));
+    /// assert_eq!(live.get(1), Some(&PauliDense::Z));
+    /// # }
+    /// ```
+    pub fn track_pauli_string_masked(
+        &mut self,
+        string: &PauliString<PauliTuple>,
+        mask: &[bool],
+    ) {
+        assert_eq!(
+            string.len(),
+            mask.len(),
+            "track_pauli_string_masked: the mask has to have the length of the string"
+        );
+        for ((bit, pauli), _) in string.iter().zip(mask).filter(|(_, flag)| **flag) {
+            self.track_pauli(*bit, P::new_product(pauli.get_z(), pauli.get_x()));
+        }
+    }
+}
+
 /// Note that the inner storage type is basically a vector. Therefore, the it may
 /// contain buffer qubits, even though they were not explicitly initialized.
 impl<S, P> Tracker for Live<S>
@@ -124,6 +343,10 @@ where
     type Stack = P;
     type Pauli = P;
 
+    const NATIVE_GATES: &'static [&'static str] = &[
+        "s", "h", "sh", "hs", "shs", "cz", "cx", "cy", "swap", "zcx", "zcy", "iswap",
+    ];
+
     movements!(
         (move_x_to_x, xpx, set_x),
         (move_x_to_z, zpx, set_x),
@@ -137,6 +360,10 @@ where
         self.storage.insert(bit, P::I)
     }
 
+    fn contains_qubit(&self, bit: usize) -> bool {
+        self.storage.get(bit).is_some()
+    }
+
     fn track_pauli(&mut self, bit: usize, pauli: Self::Pauli) {
         if let Some(p) = self.storage.get_mut(bit) {
             p.multiply(pauli)
@@ -211,7 +438,7 @@ mod tests {
     use super::*;
     use crate::{
         collection::{BufferedVector, Map},
-        pauli::{PauliDense, PauliEnum, PauliTuple},
+        pauli::{InvalidU8, PauliDense, PauliEnum, PauliTuple},
     };
 
     trait Pw: Pauli + Copy + Clone + Default + Into<PauliDense> + From<PauliDense> {}
@@ -325,5 +552,105 @@ mod tests {
         assert_eq!(tracker.new_qubit(0), None);
     }
 
-    //
+    #[test]
+    fn apply_frames() {
+        use crate::{pauli::PauliStack, tracker::frames::Frames};
+
+        const NUM: usize = 4;
+        let mut frames = Frames::<Map<PauliStack<Vec<bool>>>>::init(NUM);
+        let mut live = Live::<PauliDense>::init(NUM);
+        let outcomes = [true, false, true, true, false, true];
+        for (i, outcome) in outcomes.into_iter().enumerate() {
+            frames.track_y(i % NUM);
+            if outcome {
+                live.track_y(i % NUM);
+            }
+            frames.cy(i % NUM, (i + 3) % NUM);
+            live.cy(i % NUM, (i + 3) % NUM);
+            frames.sh((i + 1) % NUM);
+            live.sh((i + 1) % NUM);
+        }
+
+        // the manual collapse
+        let mut check = vec![PauliDense::new_i(); NUM];
+        for (bit, stack) in frames.as_storage().iter() {
+            check[*bit] = PauliDense::from(stack.sum_up(&outcomes));
+        }
+        let mut applied = Live::<PauliDense>::init(2);
+        applied.apply_frames(&frames, &outcomes);
+        assert_eq!(applied.as_ref().0, check);
+        assert_eq!(applied, live);
+
+        // multiplying in the same correction again cancels it
+        applied.apply_frames(&frames, &outcomes);
+        assert_eq!(applied.as_ref().0, vec![PauliDense::new_i(); NUM]);
+    }
+    #[test]
+    fn outcome_parity() {
+        let mut live = Live::<PauliDense>::init(3);
+        let mut check = Live::<PauliDense>::init(3);
+        assert!(!live.outcome_parity());
+        let mut parity = false;
+        let outcomes = [true, true, false, true, false, false, false];
+        for (i, outcome) in outcomes.into_iter().enumerate() {
+            let pauli = PauliDense::try_from((i % 3 + 1) as u8).unwrap();
+            live.track_pauli_if(i % 3, pauli, outcome);
+            if outcome {
+                check.track_pauli(i % 3, pauli);
+            }
+            parity ^= outcome;
+            assert_eq!(live.outcome_parity(), parity);
+        }
+        assert!(live.outcome_parity());
+        assert_eq!(live.as_storage(), check.as_storage());
+    }
+
+    #[test]
+    fn debug() {
+        let mut live = Live::<PauliTuple>::init(2);
+        live.track_x(1);
+        assert_eq!(
+            format!("{live:?}"),
+            "Live { storage: BufferedVector([PauliTuple(false, false), \
+             PauliTuple(false, true)]), outcome_parity: false }"
+        );
+        let live = Live::<PauliTuple>::init(5000);
+        assert_eq!(format!("{live:?}"), "Live { storage: .., outcome_parity: false }");
+        assert!(format!("{live:#?}").len() > 5000);
+    }
+
+    #[test]
+    fn track_pauli_byte() {
+        let mut live = Live::<PauliEnum>::init(3);
+        let mut check = Live::<PauliEnum>::init(3);
+        for (i, byte) in [2, 3, 1, 0, 3].into_iter().enumerate() {
+            live.track_pauli_byte(i % 3, byte).unwrap();
+            check.track_pauli(i % 3, PauliDense::try_from(byte).unwrap().into());
+        }
+        assert_eq!(live.as_storage(), check.as_storage());
+        assert_eq!(live.track_pauli_byte(0, 7), Err(InvalidU8(7)));
+        assert_eq!(live.as_storage(), check.as_storage());
+    }
+
+    #[test]
+    fn track_pauli_string_masked() {
+        let string = vec![
+            (0, PauliTuple::X),
+            (1, PauliTuple::Y),
+            (2, PauliTuple::Z),
+            (0, PauliTuple::Z),
+        ];
+        let mut live = Live::<PauliDense>::init(3);
+        live.track_pauli_if(1, PauliDense::X, true);
+        live.track_pauli_string_masked(&string, &[true, false, true, true]);
+        let mut check = Live::<PauliDense>::init(3);
+        check.track_pauli_if(1, PauliDense::X, true);
+        check.track_y(0);
+        check.track_z(2);
+        assert_eq!(live.as_storage(), check.as_storage());
+        assert!(live.outcome_parity());
+
+        live.track_pauli_string_masked(&string, &[false; 4]);
+        assert_eq!(live.as_storage(), check.as_storage());
+    }
 }
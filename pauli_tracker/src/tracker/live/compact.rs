@@ -0,0 +1,217 @@
+/*!
+A compact (de)serialization of the [Live] tracker.
+
+The Paulis of the qubits `0..n` are (de)serialized as a single string, where the i-th
+character is the [tableau encoding](crate::pauli::tableau_encoding) of qubit i's Pauli
+as a digit, e.g., `"0231"` for `I X Y Z`. An odd [outcome parity](Live::outcome_parity)
+is marked with a leading `-`, e.g., `"-0231"`. Use it via serde's `with` attribute:
+
+```
+# fn main() { #![cfg_attr(coverage_nightly, coverage(off))]
+use pauli_tracker::{
+    collection::{Init, NaiveVector},
+    pauli::{Pauli, PauliDense},
+    tracker::{Tracker, live::{self, Live}},
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Corrections {
+    #[serde(with = "live::compact")]
+    live: Live<NaiveVector<PauliDense>>,
+}
+
+let mut live = Live::<NaiveVector<PauliDense>>::init(4);
+live.track_x(1);
+live.track_y(2);
+live.track_z(3);
+let corrections = Corrections { live };
+let json = serde_json::to_string(&corrections).unwrap();
+assert_eq!(json, r#"{"live":"0231"}"#);
+assert_eq!(serde_json::from_str::<Corrections>(&json).unwrap(), corrections);
+
+let mut live = corrections.live;
+live.track_pauli_if(0, PauliDense::Z, true);
+let corrections = Corrections { live };
+let json = serde_json::to_string(&corrections).unwrap();
+assert_eq!(json, r#"{"live":"-1231"}"#);
+assert_eq!(serde_json::from_str::<Corrections>(&json).unwrap(), corrections);
+# }
+```
+
+The storage must contain exactly the qubits `0..n`, otherwise the serialization
+errors.
+*/
+
+use std::fmt;
+
+use serde::{
+    Deserializer, Serializer,
+    de::{self, Visitor},
+    ser,
+};
+
+use super::Live;
+use crate::{
+    collection::{Base, Init, Iterable},
+    pauli::{Pauli, PauliTuple, from_tableau_bytes},
+};
+
+/// Serialize the [Live] tracker as a string of tableau encodings. Compare the
+/// [module documentation](self).
+pub fn serialize<S, P, Ser>(
+    live: &Live<S>,
+    serializer: Ser,
+) -> Result<Ser::Ok, Ser::Error>
+where
+    S: Iterable<TI = P>,
+    P: Pauli,
+    Ser: Serializer,
+{
+    let mut pairs = live.as_storage().iter_pairs().collect::<Vec<_>>();
+    pairs.sort_by_key(|(bit, _)| *bit);
+    let mut string = String::with_capacity(pairs.len() + 1);
+    if live.outcome_parity {
+        string.push('-');
+    }
+    for (i, (bit, pauli)) in pairs.into_iter().enumerate() {
+        if i != bit {
+            return Err(ser::Error::custom(format!(
+                "the compact representation requires the qubits 0..n, but qubit {i} is \
+                 missing"
+            )));
+        }
+        string.push(char::from(b'0' + pauli.tableau_encoding()));
+    }
+    serializer.serialize_str(&string)
+}
+
+/// Deserialize the [Live] tracker from a string of tableau encodings. Compare the
+/// [module documentation](self).
+pub fn deserialize<'de, S, P, D>(deserializer: D) -> Result<Live<S>, D::Error>
+where
+    S: Init + Base<TB = P>,
+    P: Pauli + From<PauliTuple>,
+    D: Deserializer<'de>,
+{
+    struct CompactVisitor;
+    impl Visitor<'_> for CompactVisitor {
+        type Value = (Vec<PauliTuple>, bool);
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str(
+                "a string of tableau encodings, i.e., of the digits 0 to 3, optionally \
+                 prefixed with '-'",
+            )
+        }
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+            let (v, outcome_parity) = match v.strip_prefix('-') {
+                Some(rest) => (rest, true),
+                None => (v, false),
+            };
+            let bytes = v.bytes().map(|b| b.wrapping_sub(b'0')).collect::<Vec<_>>();
+            Ok((from_tableau_bytes(&bytes).map_err(E::custom)?, outcome_parity))
+        }
+    }
+
+    let (paulis, outcome_parity) = deserializer.deserialize_str(CompactVisitor)?;
+    // insert the qubits in order, so that it also works for the NaiveVector
+    let mut storage = S::init(0);
+    for (bit, pauli) in paulis.into_iter().enumerate() {
+        storage.insert(bit, pauli.into());
+    }
+    let mut live = Live::new(storage);
+    live.outcome_parity = outcome_parity;
+    Ok(live)
+}
+
+#[cfg(test)]
+mod tests {
+    use coverage_helper::test;
+    use serde::{Deserialize, Serialize};
+
+    use super::*;
+    use crate::{
+        collection::{Map, NaiveVector},
+        pauli::PauliDense,
+        tracker::Tracker,
+    };
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Wrapper<S> {
+        #[serde(with = "super")]
+        #[serde(bound(
+            serialize = "S: Iterable, S::TI: Pauli",
+            deserialize = "S: Init + Base, S::TB: Pauli + From<PauliTuple>"
+        ))]
+        live: Live<S>,
+    }
+
+    #[test]
+    fn roundtrip() {
+        let mut live = Live::<NaiveVector<PauliDense>>::init(5);
+        live.track_x(0);
+        live.track_y(3);
+        live.track_z(4);
+        live.track_z(0);
+        let wrapper = Wrapper { live };
+        let json = serde_json::to_string(&wrapper).unwrap();
+        assert_eq!(json, r#"{"live":"30031"}"#);
+        assert_eq!(
+            serde_json::from_str::<Wrapper<NaiveVector<PauliDense>>>(&json).unwrap(),
+            wrapper
+        );
+
+        let map = Wrapper {
+            live: Live::<Map<PauliDense>>::init(3),
+        };
+        let json = serde_json::to_string(&map).unwrap();
+        assert_eq!(json, r#"{"live":"000"}"#);
+        assert_eq!(serde_json::from_str::<Wrapper<Map<PauliDense>>>(&json).unwrap(), map);
+
+        let mut live = Live::<Map<PauliDense>>::init(2);
+        live.track_pauli_if(1, PauliDense::new_x(), true);
+        live.track_pauli_if(0, PauliDense::new_x(), true);
+        live.track_pauli_if(0, PauliDense::new_y(), true);
+        assert!(live.outcome_parity());
+        let parity = Wrapper { live };
+        let json = serde_json::to_string(&parity).unwrap();
+        assert_eq!(json, r#"{"live":"-12"}"#);
+        let deserialized =
+            serde_json::from_str::<Wrapper<Map<PauliDense>>>(&json).unwrap();
+        assert!(deserialized.live.outcome_parity());
+        assert_eq!(deserialized, parity);
+
+        let empty = Wrapper {
+            live: Live::<NaiveVector<PauliDense>>::init(0),
+        };
+        let json = serde_json::to_string(&empty).unwrap();
+        assert_eq!(json, r#"{"live":""}"#);
+        assert!(
+            serde_json::from_str::<Wrapper<NaiveVector<PauliDense>>>(r#"{"live":"-"}"#)
+                .unwrap()
+                .live
+                .outcome_parity()
+        );
+    }
+
+    #[test]
+    fn errors() {
+        let mut live = Live::<Map<PauliDense>>::init(3);
+        live.measure(1).unwrap();
+        assert!(serde_json::to_string(&Wrapper { live }).is_err());
+        assert!(
+            serde_json::from_str::<Wrapper<NaiveVector<PauliDense>>>(r#"{"live":"014"}"#)
+                .is_err()
+        );
+        assert!(
+            serde_json::from_str::<Wrapper<NaiveVector<PauliDense>>>(r#"{"live":"0a"}"#)
+                .is_err()
+        );
+        // the sign is only allowed once and only at the front
+        for json in [r#"{"live":"--01"}"#, r#"{"live":"0-1"}"#] {
+            assert!(
+                serde_json::from_str::<Wrapper<NaiveVector<PauliDense>>>(json).is_err()
+            );
+        }
+    }
+}
@@ -0,0 +1,249 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::Live;
+use crate::{
+    collection::{Init, IterableBase},
+    pauli::{Pauli, PauliTuple},
+    tracker::{MissingBit, PauliString, Tracker},
+};
+
+/// A [Live] tracker that additionally records a snapshot of all tracked Paulis after
+/// each [track_pauli](Tracker::track_pauli) and
+/// [track_pauli_string](Tracker::track_pauli_string), e.g., to trace when an
+/// unexpected correction appeared during a long run.
+///
+/// This is opt-in, via [Live::with_history], since the snapshots copy the whole state.
+/// All other operations are forwarded to the inner [Live] tracker without recording.
+///
+/// # Examples
+/// ```
+/// # fn main() { #![cfg_attr(coverage_nightly, coverage(off))]
+/// # use pauli_tracker::{collection::{Init, Map}, pauli::{Pauli,
+/// #     PauliTuple}, tracker::{Tracker, live::Live}};
+/// let mut tracker = Live::<Map<PauliTuple>>::init(2).with_history();
+/// tracker.track_x(0);
+/// tracker.cx(0, 1);
+/// tracker.track_z(1);
+/// let history = tracker.history();
+/// assert_eq!(history.len(), 2);
+/// assert_eq!(history[0], [(0, PauliTuple::X), (1, PauliTuple::I)]);
+/// assert_eq!(history[1], [(0, PauliTuple::X), (1, PauliTuple::Y)]);
+/// // measured qubits are missing in later snapshots, without shifting the others
+/// tracker.measure(0).unwrap();
+/// tracker.track_x(1);
+/// assert_eq!(tracker.history()[2], [(1, PauliTuple::Z)]);
+/// # }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LiveWithHistory<Storage> {
+    live: Live<Storage>,
+    history: Vec<PauliString<PauliTuple>>,
+}
+
+impl<S> Live<S> {
+    /// Convert the tracker into a [LiveWithHistory], which records a snapshot of the
+    /// tracked Paulis after each tracking operation.
+    pub fn with_history(self) -> LiveWithHistory<S> {
+        LiveWithHistory {
+            live: self,
+            history: Vec::new(),
+        }
+    }
+}
+
+impl<S> LiveWithHistory<S> {
+    /// Reference the underlining [Live] tracker.
+    pub fn live(&self) -> &Live<S> {
+        &self.live
+    }
+
+    /// Get the recorded snapshots, one for each tracking operation. Each snapshot
+    /// contains the qubits together with their Paulis, ordered by the qubits' indices.
+    pub fn history(&self) -> &[PauliString<PauliTuple>] {
+        &self.history
+    }
+
+    /// Convert the object into the underlining [Live] tracker and the history.
+    pub fn into_parts(self) -> (Live<S>, Vec<PauliString<PauliTuple>>) {
+        (self.live, self.history)
+    }
+}
+
+impl<S: Init> Init for LiveWithHistory<S> {
+    fn init(len: usize) -> Self {
+        Live::init(len).with_history()
+    }
+}
+
+impl<S, P> LiveWithHistory<S>
+where
+    S: IterableBase<T = P>,
+    P: Pauli,
+{
+    fn record(&mut self) {
+        let mut snapshot = self
+            .live
+            .as_storage()
+            .iter_pairs()
+            .map(|(bit, pauli)| {
+                (bit, PauliTuple::new_product(pauli.get_z(), pauli.get_x()))
+            })
+            .collect::<Vec<_>>();
+        snapshot.sort_by_key(|(bit, _)| *bit);
+        self.history.push(snapshot);
+    }
+}
+
+macro_rules! single {
+    ($($name:ident,)*) => {$(
+        fn $name(&mut self, bit: usize) {
+            self.live.$name(bit);
+        }
+    )*};
+}
+
+macro_rules! double {
+    ($($name:ident,)*) => {$(
+        fn $name(&mut self, bit_a: usize, bit_b: usize) {
+            self.live.$name(bit_a, bit_b);
+        }
+    )*};
+}
+
+impl<S, P> Tracker for LiveWithHistory<S>
+where
+    S: IterableBase<T = P>,
+    P: Pauli + Clone,
+{
+    type Stack = P;
+    type Pauli = P;
+
+    const NATIVE_GATES: &'static [&'static str] = <Live<S> as Tracker>::NATIVE_GATES;
+
+    fn new_qubit(&mut self, bit: usize) -> Option<Self::Stack> {
+        self.live.new_qubit(bit)
+    }
+
+    fn contains_qubit(&self, bit: usize) -> bool {
+        self.live.contains_qubit(bit)
+    }
+
+    fn track_pauli(&mut self, bit: usize, pauli: Self::Pauli) {
+        self.live.track_pauli(bit, pauli);
+        self.record();
+    }
+
+    fn track_pauli_string(&mut self, string: PauliString<Self::Pauli>) {
+        self.live.track_pauli_string(string);
+        self.record();
+    }
+
+    single!(
+        id,
+        x,
+        y,
+        z,
+        s,
+        sdg,
+        sz,
+        szdg,
+        hxy,
+        h,
+        sy,
+        sydg,
+        sh,
+        hs,
+        shs,
+        sx,
+        sxdg,
+        hyz,
+        v,
+        vdg,
+        remove_x,
+        remove_z,
+        prepare_zero,
+        prepare_plus,
+    );
+
+    double!(
+        cz,
+        cx,
+        cy,
+        swap,
+        zcz,
+        zcx,
+        zcy,
+        iswap,
+        iswapdg,
+        move_x_to_x,
+        move_x_to_z,
+        move_z_to_x,
+        move_z_to_z,
+    );
+
+    fn measure(&mut self, bit: usize) -> Result<Self::Stack, MissingBit> {
+        self.live.measure(bit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use coverage_helper::test;
+
+    use super::*;
+    use crate::{
+        collection::{Map, NaiveVector},
+        pauli::PauliDense,
+    };
+
+    #[test]
+    fn history() {
+        let mut tracker = Live::<Map<PauliDense>>::init(3).with_history();
+        let mut check = Live::<Map<PauliDense>>::init(3);
+        let mut expected = Vec::new();
+        #[cfg_attr(coverage_nightly, coverage(off))]
+        fn snapshot(live: &Live<Map<PauliDense>>) -> PauliString<PauliTuple> {
+            (0..3)
+                .filter_map(|bit| live.get(bit).map(|pauli| (bit, (*pauli).into())))
+                .collect()
+        }
+
+        tracker.track_x(0);
+        check.track_x(0);
+        expected.push(snapshot(&check));
+        tracker.cx(0, 2);
+        check.cx(0, 2);
+        tracker
+            .track_pauli_string(vec![(1, PauliDense::new_y()), (2, PauliDense::new_z())]);
+        check
+            .track_pauli_string(vec![(1, PauliDense::new_y()), (2, PauliDense::new_z())]);
+        expected.push(snapshot(&check));
+        tracker.h(1);
+        check.h(1);
+        tracker.track_z(1);
+        check.track_z(1);
+        expected.push(snapshot(&check));
+        assert_eq!(tracker.measure(0), check.measure(0));
+        tracker.track_x(2);
+        check.track_x(2);
+        expected.push(snapshot(&check));
+
+        assert_eq!(tracker.history().len(), 4);
+        assert_eq!(tracker.history(), expected);
+        assert_eq!(expected[1], [
+            (0, PauliTuple::X),
+            (1, PauliTuple::Y),
+            (2, PauliTuple::Y)
+        ]);
+        assert_eq!(expected[3], [(1, PauliTuple::X), (2, PauliTuple::Z)]);
+        let (live, history) = tracker.into_parts();
+        assert_eq!(live, check);
+        assert_eq!(history, expected);
+
+        let tracker = LiveWithHistory::<NaiveVector<PauliTuple>>::init(2);
+        assert_eq!(tracker.live(), &Live::init(2));
+        assert!(tracker.history().is_empty());
+    }
+}
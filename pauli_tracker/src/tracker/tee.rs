@@ -0,0 +1,217 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::{MissingBit, PauliString, Tracker};
+use crate::collection::Init;
+
+/// A [Tracker] that forwards all operations to two inner trackers, e.g., to run the same
+/// circuit through a [Frames](super::frames::Frames) and a [Live](super::live::Live)
+/// tracker for cross-validation, without duplicating all the calls.
+///
+/// Both trackers have to use the same [Pauli](crate::pauli::Pauli) type. Every gate is
+/// forwarded directly, i.e., if one of the trackers implements a gate natively, its
+/// native implementation is used (independent of [NATIVE_GATES](Tracker::NATIVE_GATES)).
+///
+/// # Examples
+/// ```
+/// # fn main() { #![cfg_attr(coverage_nightly, coverage(off))]
+/// # use pauli_tracker::{collection::{BufferedVector, Init}, pauli::{Pauli, PauliStack,
+/// #     PauliTuple}, tracker::{Tracker, TeeTracker, frames::Frames, live::Live}};
+/// let mut tracker = TeeTracker::<
+///     Frames<BufferedVector<PauliStack<Vec<bool>>>>,
+///     Live<BufferedVector<PauliTuple>>,
+/// >::init(2);
+/// tracker.track_x(0);
+/// tracker.cx(0, 1);
+/// let (frames, live) = tracker.into_parts();
+/// assert_eq!(frames.get_frame(0), Some(vec![(0, PauliTuple::X), (1, PauliTuple::X)]));
+/// assert_eq!((live[0], live[1]), (PauliTuple::X, PauliTuple::X));
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TeeTracker<A, B> {
+    first: A,
+    second: B,
+}
+
+impl<A, B> TeeTracker<A, B> {
+    /// Create a new [TeeTracker] from the two trackers.
+    ///
+    /// The trackers should describe the same qubits, otherwise the gates might panic in
+    /// only one of them.
+    pub fn new(first: A, second: B) -> Self {
+        Self { first, second }
+    }
+
+    /// Reference the first tracker.
+    pub fn first(&self) -> &A {
+        &self.first
+    }
+
+    /// Reference the second tracker.
+    pub fn second(&self) -> &B {
+        &self.second
+    }
+
+    /// Convert the object into the two trackers.
+    pub fn into_parts(self) -> (A, B) {
+        (self.first, self.second)
+    }
+}
+
+impl<A: Init, B: Init> Init for TeeTracker<A, B> {
+    fn init(len: usize) -> Self {
+        Self::new(A::init(len), B::init(len))
+    }
+}
+
+macro_rules! single {
+    ($($name:ident,)*) => {$(
+        fn $name(&mut self, bit: usize) {
+            self.first.$name(bit);
+            self.second.$name(bit);
+        }
+    )*};
+}
+
+macro_rules! double {
+    ($($name:ident,)*) => {$(
+        fn $name(&mut self, bit_a: usize, bit_b: usize) {
+            self.first.$name(bit_a, bit_b);
+            self.second.$name(bit_a, bit_b);
+        }
+    )*};
+}
+
+impl<A, B> Tracker for TeeTracker<A, B>
+where
+    A: Tracker,
+    B: Tracker<Pauli = A::Pauli>,
+    A::Pauli: Clone,
+{
+    type Stack = (A::Stack, B::Stack);
+    type Pauli = A::Pauli;
+
+    /// Insert the qu`bit` into both trackers. Only if both trackers return an old value,
+    /// it is returned.
+    fn new_qubit(&mut self, bit: usize) -> Option<Self::Stack> {
+        let first = self.first.new_qubit(bit);
+        let second = self.second.new_qubit(bit);
+        first.zip(second)
+    }
+
+    fn contains_qubit(&self, bit: usize) -> bool {
+        self.first.contains_qubit(bit) && self.second.contains_qubit(bit)
+    }
+
+    fn track_pauli(&mut self, bit: usize, pauli: Self::Pauli) {
+        self.first.track_pauli(bit, pauli.clone());
+        self.second.track_pauli(bit, pauli);
+    }
+
+    fn track_pauli_string(&mut self, string: PauliString<Self::Pauli>) {
+        self.first.track_pauli_string(string.clone());
+        self.second.track_pauli_string(string);
+    }
+
+    single!(
+        id,
+        x,
+        y,
+        z,
+        s,
+        sdg,
+        sz,
+        szdg,
+        hxy,
+        h,
+        sy,
+        sydg,
+        sh,
+        hs,
+        shs,
+        sx,
+        sxdg,
+        hyz,
+        v,
+        vdg,
+        remove_x,
+        remove_z,
+        prepare_zero,
+        prepare_plus,
+    );
+
+    double!(
+        cz,
+        cx,
+        cy,
+        swap,
+        zcz,
+        zcx,
+        zcy,
+        iswap,
+        iswapdg,
+        move_x_to_x,
+        move_x_to_z,
+        move_z_to_x,
+        move_z_to_z,
+    );
+
+    /// Measure the qu`bit` in both trackers. If it is missing in one of them, the
+    /// according error is returned (but it is still removed from the other tracker).
+    fn measure(&mut self, bit: usize) -> Result<Self::Stack, MissingBit> {
+        let first = self.first.measure(bit);
+        let second = self.second.measure(bit);
+        Ok((first?, second?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use coverage_helper::test;
+
+    use super::*;
+    use crate::{
+        collection::{BufferedVector, Map},
+        pauli::{Pauli, PauliStack, PauliTuple},
+        tracker::{frames::Frames, live::Live},
+    };
+
+    type ThisFrames = Frames<Map<PauliStack<Vec<bool>>>>;
+    type ThisLive = Live<BufferedVector<PauliTuple>>;
+
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn program(tracker: &mut impl Tracker<Pauli = PauliTuple>) {
+        tracker.track_x(0);
+        tracker.cx(0, 1);
+        tracker.track_pauli_string(vec![(2, PauliTuple::Y), (3, PauliTuple::Z)]);
+        tracker.iswap(1, 2);
+        tracker.sh(3);
+        tracker.cy(3, 0);
+        tracker.move_z_to_x(1, 3);
+        tracker.sxdg(2);
+        tracker.track_z(1);
+        tracker.zcy(2, 0);
+    }
+
+    #[test]
+    fn forwarding() {
+        let mut tee = TeeTracker::<ThisFrames, ThisLive>::init(4);
+        program(&mut tee);
+        let mut frames = ThisFrames::init(4);
+        program(&mut frames);
+        let mut live = ThisLive::init(4);
+        program(&mut live);
+
+        assert_eq!(tee.first(), &frames);
+        assert_eq!(tee.second(), &live);
+
+        assert_eq!(
+            tee.measure(3),
+            Ok((frames.measure(3).unwrap(), live.measure(3).unwrap()))
+        );
+        assert_eq!(tee.measure(3), Err(MissingBit(3)));
+        assert_eq!(tee.into_parts(), (frames, live));
+    }
+}